@@ -0,0 +1,5 @@
+//! Spectral/feature analysis subsystem
+
+pub mod spectral;
+
+pub use spectral::{analyze_windowed, AnalysisReport, Analyzer, SpectralAnalyzer, SummaryStat};
@@ -0,0 +1,534 @@
+use crate::core::AudioFrame;
+use crate::decoder::Decoder;
+use crate::error::AudioResult;
+use crate::processor::Segment;
+use rustfft::num_complex::Complex32;
+use rustfft::{Fft, FftPlanner};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Number of pitch classes in the chroma descriptor (one per semitone)
+const CHROMA_BINS: usize = 12;
+/// Reference frequency (A4) pitch classes are measured relative to
+const CHROMA_REFERENCE_HZ: f32 = 440.0;
+/// Floor applied to RMS-derived dB loudness to avoid `-inf` on silence
+const LOUDNESS_FLOOR_DB: f32 = -100.0;
+
+/// FFT window size in samples
+const WINDOW_SIZE: usize = 2048;
+/// Hop size between successive windows (50% overlap)
+const HOP_SIZE: usize = WINDOW_SIZE / 2;
+/// Fraction of total spectral energy below the rolloff frequency
+const ROLLOFF_ENERGY_FRACTION: f32 = 0.85;
+/// Tempo search range
+const MIN_TEMPO_BPM: f64 = 40.0;
+const MAX_TEMPO_BPM: f64 = 220.0;
+
+/// Mean/variance summary of a per-window feature across the whole file
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SummaryStat {
+    /// Mean of the per-window values
+    pub mean: f64,
+    /// Variance of the per-window values
+    pub variance: f64,
+}
+
+impl SummaryStat {
+    fn from_values(values: &[f32]) -> Self {
+        if values.is_empty() {
+            return SummaryStat::default();
+        }
+
+        let n = values.len() as f64;
+        let mean = values.iter().map(|&v| v as f64).sum::<f64>() / n;
+        let variance = values
+            .iter()
+            .map(|&v| {
+                let d = v as f64 - mean;
+                d * d
+            })
+            .sum::<f64>()
+            / n;
+
+        SummaryStat { mean, variance }
+    }
+}
+
+/// Aggregated descriptors for a whole decoded file
+#[derive(Debug, Clone)]
+pub struct AnalysisReport {
+    /// Total duration analyzed
+    pub duration: Duration,
+    /// Sample rate of the analyzed audio
+    pub sample_rate: u32,
+    /// Short-time RMS envelope summary
+    pub rms: SummaryStat,
+    /// Short-time peak envelope summary
+    pub peak: SummaryStat,
+    /// Spectral centroid summary (Hz)
+    pub spectral_centroid: SummaryStat,
+    /// Spectral rolloff summary (Hz)
+    pub spectral_rolloff: SummaryStat,
+    /// Zero-crossing rate summary
+    pub zero_crossing_rate: SummaryStat,
+    /// RMS loudness summary, in dB
+    pub loudness_db: SummaryStat,
+    /// 12-bin chroma descriptor (relative pitch-class energy), averaged
+    /// across every analyzed window
+    pub chroma: [f32; CHROMA_BINS],
+    /// Estimated tempo in BPM, if one could be determined
+    pub estimated_tempo_bpm: Option<f32>,
+}
+
+impl AnalysisReport {
+    /// Render as a flat JSON object
+    pub fn to_json(&self) -> String {
+        fn stat_json(name: &str, stat: &SummaryStat) -> String {
+            format!(
+                "\"{name}\":{{\"mean\":{},\"variance\":{}}}",
+                stat.mean, stat.variance
+            )
+        }
+
+        let tempo = match self.estimated_tempo_bpm {
+            Some(bpm) => bpm.to_string(),
+            None => "null".to_string(),
+        };
+
+        let chroma_json = self
+            .chroma
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!(
+            "{{\"duration_secs\":{},\"sample_rate\":{},{},{},{},{},{},{},\"chroma\":[{}],\"estimated_tempo_bpm\":{}}}",
+            self.duration.as_secs_f64(),
+            self.sample_rate,
+            stat_json("rms", &self.rms),
+            stat_json("peak", &self.peak),
+            stat_json("spectral_centroid", &self.spectral_centroid),
+            stat_json("spectral_rolloff", &self.spectral_rolloff),
+            stat_json("zero_crossing_rate", &self.zero_crossing_rate),
+            stat_json("loudness_db", &self.loudness_db),
+            chroma_json,
+            tempo,
+        )
+    }
+}
+
+/// Trait for analyzers that consume `AudioFrame`s from any `Decoder` and
+/// produce aggregated descriptors once the stream is exhausted
+pub trait Analyzer {
+    /// Feed the next decoded frame into the analyzer
+    fn push_frame(&mut self, frame: &AudioFrame) -> AudioResult<()>;
+
+    /// Aggregate everything seen so far into a report
+    fn finalize(&mut self) -> AudioResult<AnalysisReport>;
+}
+
+/// Windowed-FFT spectral/temporal feature analyzer
+///
+/// Downmixes incoming frames to mono and analyzes them in 2048-sample Hann
+/// windows with 50% hop, accumulating per-window features into mean/variance
+/// summaries for the whole file.
+pub struct SpectralAnalyzer {
+    fft: Arc<dyn Fft<f32>>,
+    hann_window: Vec<f32>,
+    sample_rate: Option<u32>,
+    mono_buffer: Vec<f32>,
+    total_samples: u64,
+    rms_values: Vec<f32>,
+    peak_values: Vec<f32>,
+    centroid_values: Vec<f32>,
+    rolloff_values: Vec<f32>,
+    zcr_values: Vec<f32>,
+    loudness_values: Vec<f32>,
+    onset_envelope: Vec<f32>,
+    prev_spectral_sum: Option<f32>,
+    /// Running sum of each window's (normalized) chroma vector
+    chroma_sum: [f32; CHROMA_BINS],
+    /// Number of windows folded into `chroma_sum`
+    chroma_windows: u32,
+}
+
+impl SpectralAnalyzer {
+    /// Create a new analyzer
+    pub fn new() -> Self {
+        let mut planner = FftPlanner::new();
+        let fft = planner.plan_fft_forward(WINDOW_SIZE);
+
+        // Hann window: 0.5 * (1 - cos(2*pi*n / (N-1)))
+        let hann_window = (0..WINDOW_SIZE)
+            .map(|n| {
+                0.5 * (1.0
+                    - (2.0 * std::f32::consts::PI * n as f32 / (WINDOW_SIZE - 1) as f32).cos())
+            })
+            .collect();
+
+        SpectralAnalyzer {
+            fft,
+            hann_window,
+            sample_rate: None,
+            mono_buffer: Vec::new(),
+            total_samples: 0,
+            rms_values: Vec::new(),
+            peak_values: Vec::new(),
+            centroid_values: Vec::new(),
+            rolloff_values: Vec::new(),
+            zcr_values: Vec::new(),
+            loudness_values: Vec::new(),
+            onset_envelope: Vec::new(),
+            prev_spectral_sum: None,
+            chroma_sum: [0.0; CHROMA_BINS],
+            chroma_windows: 0,
+        }
+    }
+
+    /// Downmix an interleaved frame to mono and append to the analysis buffer
+    fn append_mono(&mut self, frame: &AudioFrame) {
+        let num_channels = frame.channels().count() as usize;
+        self.mono_buffer.extend(
+            frame
+                .samples()
+                .chunks(num_channels)
+                .map(|group| group.iter().sum::<f32>() / num_channels as f32),
+        );
+    }
+
+    /// Process every complete window currently available in `mono_buffer`,
+    /// leaving an unconsumed tail for the next call to overlap against
+    fn drain_windows(&mut self) {
+        let mut consumed = 0;
+        while consumed + WINDOW_SIZE <= self.mono_buffer.len() {
+            // Owned copy, not a borrow of `self.mono_buffer`: `analyze_window`
+            // needs `&mut self` while this window is still in use.
+            let window = self.mono_buffer[consumed..consumed + WINDOW_SIZE].to_vec();
+            self.analyze_window(&window);
+            consumed += HOP_SIZE;
+        }
+        self.mono_buffer.drain(0..consumed);
+    }
+
+    fn analyze_window(&mut self, window: &[f32]) {
+        let peak = window.iter().map(|&s| s.abs()).fold(0.0f32, f32::max);
+        let rms = (window.iter().map(|&s| s * s).sum::<f32>() / window.len() as f32).sqrt();
+
+        let zero_crossings = window
+            .windows(2)
+            .filter(|pair| pair[0].signum() != pair[1].signum())
+            .count();
+        let zcr = zero_crossings as f32 / window.len() as f32;
+
+        let mut spectrum: Vec<Complex32> = window
+            .iter()
+            .zip(self.hann_window.iter())
+            .map(|(&s, &w)| Complex32::new(s * w, 0.0))
+            .collect();
+        self.fft.process(&mut spectrum);
+
+        // Only the first half (plus DC) carries unique magnitude information
+        // for a real-valued input signal.
+        let magnitudes: Vec<f32> = spectrum[..WINDOW_SIZE / 2]
+            .iter()
+            .map(|c| c.norm())
+            .collect();
+
+        let sample_rate = self.sample_rate.unwrap_or(44100) as f32;
+        let bin_hz = sample_rate / WINDOW_SIZE as f32;
+
+        let magnitude_sum: f32 = magnitudes.iter().sum();
+        let centroid = if magnitude_sum > 0.0 {
+            magnitudes
+                .iter()
+                .enumerate()
+                .map(|(k, &m)| k as f32 * bin_hz * m)
+                .sum::<f32>()
+                / magnitude_sum
+        } else {
+            0.0
+        };
+
+        let rolloff_threshold = magnitude_sum * ROLLOFF_ENERGY_FRACTION;
+        let mut cumulative = 0.0;
+        let mut rolloff_bin = magnitudes.len().saturating_sub(1);
+        for (k, &m) in magnitudes.iter().enumerate() {
+            cumulative += m;
+            if cumulative >= rolloff_threshold {
+                rolloff_bin = k;
+                break;
+            }
+        }
+        let rolloff = rolloff_bin as f32 * bin_hz;
+
+        // Onset strength: half-wave rectified frame-to-frame increase in
+        // summed spectral magnitude.
+        let onset = match self.prev_spectral_sum {
+            Some(prev) => (magnitude_sum - prev).max(0.0),
+            None => 0.0,
+        };
+        self.prev_spectral_sum = Some(magnitude_sum);
+
+        let loudness_db = (20.0 * rms.max(10f32.powf(LOUDNESS_FLOOR_DB / 20.0)).log10())
+            .max(LOUDNESS_FLOOR_DB);
+
+        // Fold each non-DC bin into the pitch class it's nearest to, then
+        // normalize so the window's chroma vector sums to 1 (silent windows
+        // contribute an all-zero vector rather than skewing the average).
+        let mut chroma_window = [0.0f32; CHROMA_BINS];
+        for (k, &magnitude) in magnitudes.iter().enumerate().skip(1) {
+            let freq = k as f32 * bin_hz;
+            let pitch_class = (CHROMA_BINS as f32 * (freq / CHROMA_REFERENCE_HZ).log2()).round() as i32;
+            let bin = pitch_class.rem_euclid(CHROMA_BINS as i32) as usize;
+            chroma_window[bin] += magnitude;
+        }
+        let chroma_sum: f32 = chroma_window.iter().sum();
+        if chroma_sum > 0.0 {
+            for value in chroma_window.iter_mut() {
+                *value /= chroma_sum;
+            }
+        }
+        for (sum, value) in self.chroma_sum.iter_mut().zip(chroma_window.iter()) {
+            *sum += value;
+        }
+        self.chroma_windows += 1;
+
+        self.peak_values.push(peak);
+        self.rms_values.push(rms);
+        self.zcr_values.push(zcr);
+        self.centroid_values.push(centroid);
+        self.rolloff_values.push(rolloff);
+        self.loudness_values.push(loudness_db);
+        self.onset_envelope.push(onset);
+    }
+
+    /// Estimate tempo via autocorrelation of the onset-strength envelope,
+    /// searching lags corresponding to `MIN_TEMPO_BPM..=MAX_TEMPO_BPM`
+    fn estimate_tempo(&self, sample_rate: u32) -> Option<f32> {
+        if self.onset_envelope.len() < 2 {
+            return None;
+        }
+
+        let hop_duration_secs = HOP_SIZE as f64 / sample_rate as f64;
+        let min_lag = ((60.0 / MAX_TEMPO_BPM) / hop_duration_secs).round() as usize;
+        let max_lag = (((60.0 / MIN_TEMPO_BPM) / hop_duration_secs).round() as usize)
+            .min(self.onset_envelope.len().saturating_sub(1));
+
+        if min_lag == 0 || min_lag >= max_lag {
+            return None;
+        }
+
+        let mut best_lag = min_lag;
+        let mut best_corr = f64::MIN;
+        for lag in min_lag..=max_lag {
+            let corr: f64 = (0..self.onset_envelope.len() - lag)
+                .map(|i| self.onset_envelope[i] as f64 * self.onset_envelope[i + lag] as f64)
+                .sum();
+            if corr > best_corr {
+                best_corr = corr;
+                best_lag = lag;
+            }
+        }
+
+        if best_corr <= 0.0 {
+            return None;
+        }
+
+        Some((60.0 / (best_lag as f64 * hop_duration_secs)) as f32)
+    }
+}
+
+impl Default for SpectralAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Stream an entire decoder through a windowed [`Segment`] front-end,
+/// pushing each emitted window into a [`SpectralAnalyzer`] and reducing the
+/// whole file into one [`AnalysisReport`]
+///
+/// The segmenter's sample rate is learned from the decoder's first frame, so
+/// `window`/`hop` are given as `Duration` rather than a fixed sample count.
+pub fn analyze_windowed(
+    decoder: &mut dyn Decoder,
+    window: Duration,
+    hop: Duration,
+) -> AudioResult<AnalysisReport> {
+    let mut analyzer = SpectralAnalyzer::new();
+    let mut segmenter: Option<Segment> = None;
+    // Overlapping windows repeat samples, so the reported duration is counted
+    // from the decoded frames rather than from what the analyzer was fed.
+    let mut decoded_samples: u64 = 0;
+
+    while let Some(frame) = decoder.decode_frame()? {
+        decoded_samples += frame.samples_per_channel() as u64;
+        if segmenter.is_none() {
+            segmenter = Some(Segment::windowed(window, hop, frame.sample_rate(), true)?);
+        }
+        let segmenter = segmenter.as_mut().expect("just initialized above");
+
+        for window_frame in segmenter.split_windowed(&frame)? {
+            analyzer.push_frame(&window_frame)?;
+        }
+    }
+
+    if let Some(mut segmenter) = segmenter {
+        if let Some(tail) = segmenter.finish_windowed()? {
+            analyzer.push_frame(&tail)?;
+        }
+    }
+
+    let mut report = analyzer.finalize()?;
+    report.duration = Duration::from_secs_f64(decoded_samples as f64 / report.sample_rate as f64);
+    Ok(report)
+}
+
+impl Analyzer for SpectralAnalyzer {
+    fn push_frame(&mut self, frame: &AudioFrame) -> AudioResult<()> {
+        self.sample_rate.get_or_insert(frame.sample_rate());
+        self.total_samples += frame.samples_per_channel() as u64;
+        self.append_mono(frame);
+        self.drain_windows();
+        Ok(())
+    }
+
+    fn finalize(&mut self) -> AudioResult<AnalysisReport> {
+        let sample_rate = self.sample_rate.unwrap_or(44100);
+
+        let mut chroma = self.chroma_sum;
+        if self.chroma_windows > 0 {
+            for value in chroma.iter_mut() {
+                *value /= self.chroma_windows as f32;
+            }
+        }
+
+        Ok(AnalysisReport {
+            duration: Duration::from_secs_f64(self.total_samples as f64 / sample_rate as f64),
+            sample_rate,
+            rms: SummaryStat::from_values(&self.rms_values),
+            peak: SummaryStat::from_values(&self.peak_values),
+            spectral_centroid: SummaryStat::from_values(&self.centroid_values),
+            spectral_rolloff: SummaryStat::from_values(&self.rolloff_values),
+            zero_crossing_rate: SummaryStat::from_values(&self.zcr_values),
+            loudness_db: SummaryStat::from_values(&self.loudness_values),
+            chroma,
+            estimated_tempo_bpm: self.estimate_tempo(sample_rate),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Channels;
+
+    #[test]
+    fn test_analyze_silence_has_zero_features() {
+        let mut analyzer = SpectralAnalyzer::new();
+        let frame = AudioFrame::new(vec![0.0; WINDOW_SIZE * 4], 44100, Channels::Mono, 0).unwrap();
+
+        analyzer.push_frame(&frame).unwrap();
+        let report = analyzer.finalize().unwrap();
+
+        assert_eq!(report.rms.mean, 0.0);
+        assert_eq!(report.peak.mean, 0.0);
+    }
+
+    #[test]
+    fn test_analyze_tone_detects_spectral_centroid_near_tone_frequency() {
+        let sample_rate = 44100;
+        let freq = 1000.0;
+        let samples: Vec<f32> = (0..sample_rate * 2)
+            .map(|i| (i as f32 * freq * std::f32::consts::TAU / sample_rate as f32).sin())
+            .collect();
+        let frame = AudioFrame::new(samples, sample_rate, Channels::Mono, 0).unwrap();
+
+        let mut analyzer = SpectralAnalyzer::new();
+        analyzer.push_frame(&frame).unwrap();
+        let report = analyzer.finalize().unwrap();
+
+        assert!((report.spectral_centroid.mean - freq as f64).abs() < 200.0);
+    }
+
+    #[test]
+    fn test_analyze_tone_chroma_peaks_at_nearest_pitch_class() {
+        let sample_rate = 44100;
+        // A4 = 440Hz is pitch class 0 by definition.
+        let samples: Vec<f32> = (0..sample_rate * 2)
+            .map(|i| (i as f32 * 440.0 * std::f32::consts::TAU / sample_rate as f32).sin())
+            .collect();
+        let frame = AudioFrame::new(samples, sample_rate, Channels::Mono, 0).unwrap();
+
+        let mut analyzer = SpectralAnalyzer::new();
+        analyzer.push_frame(&frame).unwrap();
+        let report = analyzer.finalize().unwrap();
+
+        let (max_bin, _) = report
+            .chroma
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+            .unwrap();
+        assert_eq!(max_bin, 0);
+    }
+
+    #[test]
+    fn test_analyze_silence_loudness_is_floored() {
+        let mut analyzer = SpectralAnalyzer::new();
+        let frame = AudioFrame::new(vec![0.0; WINDOW_SIZE * 4], 44100, Channels::Mono, 0).unwrap();
+
+        analyzer.push_frame(&frame).unwrap();
+        let report = analyzer.finalize().unwrap();
+
+        assert!((report.loudness_db.mean - LOUDNESS_FLOOR_DB as f64).abs() < 0.01);
+    }
+
+    /// Decoder stub yielding a single fixed-size tone frame, for exercising
+    /// `analyze_windowed`'s end-to-end decode -> segment -> analyze path
+    struct ToneDecoder {
+        samples: Option<Vec<f32>>,
+        sample_rate: u32,
+    }
+
+    impl crate::decoder::Decoder for ToneDecoder {
+        fn decode_frame(&mut self) -> AudioResult<Option<AudioFrame>> {
+            match self.samples.take() {
+                Some(samples) => Ok(Some(AudioFrame::new(
+                    samples,
+                    self.sample_rate,
+                    Channels::Mono,
+                    0,
+                )?)),
+                None => Ok(None),
+            }
+        }
+
+        fn is_finished(&self) -> bool {
+            self.samples.is_none()
+        }
+    }
+
+    #[test]
+    fn test_analyze_windowed_streams_through_segment() {
+        let sample_rate = 44100u32;
+        let samples: Vec<f32> = (0..sample_rate)
+            .map(|i| (i as f32 * 1000.0 * std::f32::consts::TAU / sample_rate as f32).sin())
+            .collect();
+        let mut decoder = ToneDecoder {
+            samples: Some(samples),
+            sample_rate,
+        };
+
+        let report = analyze_windowed(
+            &mut decoder,
+            Duration::from_millis(50),
+            Duration::from_millis(25),
+        )
+        .unwrap();
+
+        assert!((report.duration.as_secs_f64() - 1.0).abs() < 0.1);
+        assert!((report.spectral_centroid.mean - 1000.0).abs() < 200.0);
+    }
+}
@@ -0,0 +1,231 @@
+//! Sample-format conversion between `AudioFrame`'s interleaved `f32` samples
+//! and packed PCM byte buffers, plus interleaved/planar layout conversion.
+
+use crate::core::{AudioFrame, BitDepth, Channels};
+use crate::error::{AudioError, AudioResult};
+
+/// Describes a packed PCM sample format: bit depth plus channel layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampleFormat {
+    /// Per-sample numeric representation
+    pub depth: BitDepth,
+    /// `true` if channels are stored as separate contiguous planes rather
+    /// than interleaved sample groups
+    pub planar: bool,
+}
+
+impl SampleFormat {
+    /// Interleaved PCM at the given bit depth
+    pub fn interleaved(depth: BitDepth) -> Self {
+        SampleFormat {
+            depth,
+            planar: false,
+        }
+    }
+
+    /// Planar PCM at the given bit depth
+    pub fn planar(depth: BitDepth) -> Self {
+        SampleFormat { depth, planar: true }
+    }
+}
+
+/// Encode one `[-1.0, 1.0]` sample as `depth`-sized little-endian bytes,
+/// appending them to `out`
+fn write_sample(sample: f32, depth: BitDepth, out: &mut Vec<u8>) {
+    let clamped = sample.clamp(-1.0, 1.0) as f64;
+    match depth {
+        BitDepth::I8 => {
+            let biased = ((clamped * 127.0).round() as i32 + 128).clamp(0, 255);
+            out.push(biased as u8);
+        }
+        BitDepth::I16 => {
+            let quantized = (clamped * i16::MAX as f64).round() as i16;
+            out.extend_from_slice(&quantized.to_le_bytes());
+        }
+        BitDepth::I24 => {
+            let quantized = (clamped * ((1i64 << 23) - 1) as f64).round() as i32;
+            out.extend_from_slice(&quantized.to_le_bytes()[..3]);
+        }
+        BitDepth::I32 => {
+            let quantized = (clamped * i32::MAX as f64).round() as i32;
+            out.extend_from_slice(&quantized.to_le_bytes());
+        }
+        BitDepth::F32 => out.extend_from_slice(&sample.to_le_bytes()),
+        BitDepth::F64 => out.extend_from_slice(&(sample as f64).to_le_bytes()),
+    }
+}
+
+/// Decode one `depth`-sized little-endian sample from `bytes` to `[-1.0, 1.0]`
+fn read_sample(bytes: &[u8], depth: BitDepth) -> f32 {
+    match depth {
+        BitDepth::I8 => (bytes[0] as f32 - 128.0) / 127.0,
+        BitDepth::I16 => i16::from_le_bytes([bytes[0], bytes[1]]) as f32 / i16::MAX as f32,
+        BitDepth::I24 => {
+            // Sign-extend the 3-byte little-endian value into an i32.
+            let raw = [bytes[0], bytes[1], bytes[2], 0];
+            let unsigned = u32::from_le_bytes(raw);
+            let signed = if unsigned & 0x0080_0000 != 0 {
+                (unsigned | 0xFF00_0000) as i32
+            } else {
+                unsigned as i32
+            };
+            signed as f32 / ((1i64 << 23) - 1) as f32
+        }
+        BitDepth::I32 => i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f32
+            / i32::MAX as f32,
+        BitDepth::F32 => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        BitDepth::F64 => f64::from_le_bytes([
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+        ]) as f32,
+    }
+}
+
+/// Split an interleaved sample buffer into one `Vec<f32>` per channel
+pub fn deinterleave(samples: &[f32], channel_count: usize) -> Vec<Vec<f32>> {
+    let mut planes = vec![Vec::with_capacity(samples.len() / channel_count.max(1)); channel_count];
+    for group in samples.chunks(channel_count) {
+        for (plane, &sample) in planes.iter_mut().zip(group) {
+            plane.push(sample);
+        }
+    }
+    planes
+}
+
+/// Merge per-channel planes back into an interleaved sample buffer
+pub fn interleave(planes: &[Vec<f32>]) -> Vec<f32> {
+    let Some(frames) = planes.first().map(Vec::len) else {
+        return Vec::new();
+    };
+    let mut samples = Vec::with_capacity(frames * planes.len());
+    for i in 0..frames {
+        for plane in planes {
+            samples.push(plane[i]);
+        }
+    }
+    samples
+}
+
+impl AudioFrame {
+    /// Pack this frame's samples into a byte buffer in the given `format`
+    pub fn to_bytes(&self, format: SampleFormat) -> Vec<u8> {
+        let bytes_per_sample = format.depth.bytes_per_sample();
+        let mut out = Vec::with_capacity(self.samples().len() * bytes_per_sample);
+
+        if format.planar {
+            let planes = deinterleave(self.samples(), self.channels().count() as usize);
+            for plane in &planes {
+                for &sample in plane {
+                    write_sample(sample, format.depth, &mut out);
+                }
+            }
+        } else {
+            for &sample in self.samples() {
+                write_sample(sample, format.depth, &mut out);
+            }
+        }
+
+        out
+    }
+
+    /// Unpack a byte buffer in the given `format` into a new frame
+    pub fn from_bytes(
+        bytes: &[u8],
+        format: SampleFormat,
+        sample_rate: u32,
+        channels: Channels,
+    ) -> AudioResult<Self> {
+        let bytes_per_sample = format.depth.bytes_per_sample();
+        if bytes_per_sample == 0 || bytes.len() % bytes_per_sample != 0 {
+            return Err(AudioError::BufferError(format!(
+                "Byte buffer length {} is not a multiple of the sample size {}",
+                bytes.len(),
+                bytes_per_sample
+            )));
+        }
+
+        let flat: Vec<f32> = bytes
+            .chunks(bytes_per_sample)
+            .map(|chunk| read_sample(chunk, format.depth))
+            .collect();
+
+        let samples = if format.planar {
+            let channel_count = channels.count() as usize;
+            if flat.len() % channel_count != 0 {
+                return Err(AudioError::BufferError(
+                    "Planar sample count not divisible by channel count".to_string(),
+                ));
+            }
+            let frames = flat.len() / channel_count;
+            let planes: Vec<Vec<f32>> = flat
+                .chunks(frames)
+                .map(|plane| plane.to_vec())
+                .collect();
+            interleave(&planes)
+        } else {
+            flat
+        };
+
+        AudioFrame::new(samples, sample_rate, channels, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_i16_round_trip() {
+        let samples = vec![0.0, 0.5, -0.5, 1.0, -1.0];
+        let frame = AudioFrame::new(samples.clone(), 44100, Channels::Mono, 0).unwrap();
+
+        let format = SampleFormat::interleaved(BitDepth::I16);
+        let bytes = frame.to_bytes(format);
+        let round_tripped = AudioFrame::from_bytes(&bytes, format, 44100, Channels::Mono).unwrap();
+
+        for (original, decoded) in samples.iter().zip(round_tripped.samples()) {
+            assert!((original - decoded).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_i24_round_trip() {
+        let samples = vec![0.3, -0.7, 0.1, -0.1];
+        let frame = AudioFrame::new(samples.clone(), 44100, Channels::Stereo, 0).unwrap();
+
+        let format = SampleFormat::interleaved(BitDepth::I24);
+        let bytes = frame.to_bytes(format);
+        assert_eq!(bytes.len(), samples.len() * 3);
+
+        let round_tripped = AudioFrame::from_bytes(&bytes, format, 44100, Channels::Stereo).unwrap();
+        for (original, decoded) in samples.iter().zip(round_tripped.samples()) {
+            assert!((original - decoded).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_planar_round_trip() {
+        let samples = vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6];
+        let frame = AudioFrame::new(samples.clone(), 44100, Channels::Stereo, 0).unwrap();
+
+        let format = SampleFormat::planar(BitDepth::F32);
+        let bytes = frame.to_bytes(format);
+        let round_tripped = AudioFrame::from_bytes(&bytes, format, 44100, Channels::Stereo).unwrap();
+
+        assert_eq!(round_tripped.samples(), samples.as_slice());
+    }
+
+    #[test]
+    fn test_deinterleave_interleave_round_trip() {
+        let samples = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let planes = deinterleave(&samples, 2);
+        assert_eq!(planes, vec![vec![1.0, 3.0, 5.0], vec![2.0, 4.0, 6.0]]);
+        assert_eq!(interleave(&planes), samples);
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_misaligned_buffer() {
+        let format = SampleFormat::interleaved(BitDepth::I16);
+        let result = AudioFrame::from_bytes(&[0u8, 1, 2], format, 44100, Channels::Mono);
+        assert!(result.is_err());
+    }
+}
@@ -193,6 +193,10 @@ pub struct AudioMetadata {
     pub bit_depth: Option<BitDepth>,
     /// Bitrate in bits per second if known
     pub bitrate: Option<u32>,
+    /// Track title if known (e.g. from a CUE sheet)
+    pub title: Option<String>,
+    /// Track performer/artist if known (e.g. from a CUE sheet)
+    pub performer: Option<String>,
 }
 
 impl AudioMetadata {
@@ -209,6 +213,8 @@ impl AudioMetadata {
             codec,
             bit_depth: None,
             bitrate: None,
+            title: None,
+            performer: None,
         })
     }
 
@@ -230,6 +236,18 @@ impl AudioMetadata {
         self
     }
 
+    /// Set track title
+    pub fn with_title(mut self, title: String) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Set track performer/artist
+    pub fn with_performer(mut self, performer: String) -> Self {
+        self.performer = Some(performer);
+        self
+    }
+
     /// Get duration in seconds
     pub fn duration_secs(&self) -> Option<f64> {
         self.duration.map(|d| d.as_secs_f64())
@@ -1,12 +1,18 @@
 //! Audio decoder implementations
 
+pub mod mp3_stream;
 pub mod symphonia;
+pub mod wav;
 
+pub use mp3_stream::StreamingMp3Decoder;
 pub use symphonia::SymphoniaDecoder;
+pub use wav::WavDecoder;
 
-use crate::core::AudioFrame;
+use crate::core::{AudioFrame, Channels};
 use crate::error::AudioResult;
+use crate::processor::stream::PcmBuffers;
 use std::path::Path;
+use std::time::Duration;
 
 /// Trait for audio decoders
 pub trait Decoder: Send {
@@ -22,10 +28,253 @@ pub trait Decoder: Send {
             "Reset not supported for this decoder".to_string(),
         ))
     }
+
+    /// Seek to the given timestamp (if supported), returning the actual
+    /// (coarse) position the decoder landed on
+    fn seek(&mut self, _time: Duration) -> AudioResult<Duration> {
+        Err(crate::error::AudioError::Unsupported(
+            "Seeking not supported for this decoder".to_string(),
+        ))
+    }
+
+    /// Total duration of the underlying stream, if known
+    fn total_duration(&self) -> AudioResult<Duration> {
+        Err(crate::error::AudioError::Unsupported(
+            "Total duration not known for this decoder".to_string(),
+        ))
+    }
+
+    /// Samples per channel not yet returned by `decode_frame`, if known
+    fn samples_remaining(&self) -> AudioResult<u64> {
+        Err(crate::error::AudioError::Unsupported(
+            "Samples remaining not known for this decoder".to_string(),
+        ))
+    }
+
+    /// Whether frames from this decoder are genuine decoded audio, or a
+    /// correctly-sized/rated placeholder emitted while the backend's real
+    /// synthesis isn't implemented yet (e.g. [`StreamingMp3Decoder`], which
+    /// emits silence until full MPEG Layer III synthesis lands). Callers
+    /// that care about actual audio content, rather than just timing and
+    /// framing, should check this before trusting the samples.
+    fn is_placeholder_audio(&self) -> bool {
+        false
+    }
+}
+
+/// Which concrete [`Decoder`] implementation [`from_file`] picks for a given
+/// input, so the dispatch logic has one place to grow as more file-backed
+/// backends are added
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Backend {
+    /// Native hound-based WAV decoder
+    Wav,
+    /// Streaming [`StreamingMp3Decoder`], fed the whole file up front
+    Mp3,
+    /// Symphonia, for everything else `from_file` knows how to open
+    Symphonia,
+}
+
+/// Select a backend for `path` by extension
+///
+/// `.wav` gets special-cased to the lightweight native decoder and `.mp3` to
+/// [`StreamingMp3Decoder`]; every other extension falls back to Symphonia,
+/// which probes the container itself.
+fn select_backend(path: &Path) -> Backend {
+    let ext = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase());
+
+    match ext.as_deref() {
+        Some("wav") => Backend::Wav,
+        Some("mp3") => Backend::Mp3,
+        _ => Backend::Symphonia,
+    }
 }
 
 /// Create a decoder from a file path
+///
+/// `.wav` inputs use the lightweight native [`WavDecoder`]; `.mp3` inputs use
+/// [`StreamingMp3Decoder`] (note its [`Decoder::is_placeholder_audio`] is
+/// currently always `true` - see that method's docs); everything else falls
+/// back to [`SymphoniaDecoder`]. See [`select_backend`] for the dispatch
+/// rule.
 pub fn from_file<P: AsRef<Path>>(path: P) -> AudioResult<Box<dyn Decoder>> {
     let path = path.as_ref();
-    SymphoniaDecoder::from_file(path).map(|d| Box::new(d) as Box<dyn Decoder>)
+
+    match select_backend(path) {
+        Backend::Wav => WavDecoder::from_file(path).map(|d| Box::new(d) as Box<dyn Decoder>),
+        Backend::Mp3 => {
+            let bytes = std::fs::read(path)?;
+            let mut decoder = StreamingMp3Decoder::new();
+            decoder.push_bytes(&bytes);
+            decoder.end_of_stream();
+            Ok(Box::new(decoder) as Box<dyn Decoder>)
+        }
+        Backend::Symphonia => {
+            SymphoniaDecoder::from_file(path).map(|d| Box::new(d) as Box<dyn Decoder>)
+        }
+    }
+}
+
+/// Pulls constant-size blocks of interleaved PCM out of any [`Decoder`],
+/// decoupling the consumer's block size from the decoder's packet
+/// granularity
+///
+/// Internally buffers decoded frames in a [`PcmBuffers`] ring; sample rate
+/// and channel layout are learned from the first decoded frame, so a decoder
+/// that never produces any audio leaves `decode_exact` always returning
+/// `Ok(None)` rather than guessing a layout.
+pub struct ExactDecoder {
+    decoder: Box<dyn Decoder>,
+    buffers: PcmBuffers,
+    format: Option<(u32, Channels)>,
+    next_frame_number: u64,
+    exhausted: bool,
+}
+
+impl ExactDecoder {
+    /// Wrap a decoder so it can be pulled in fixed-size blocks
+    pub fn new(decoder: Box<dyn Decoder>) -> Self {
+        ExactDecoder {
+            decoder,
+            buffers: PcmBuffers::new(),
+            format: None,
+            next_frame_number: 0,
+            exhausted: false,
+        }
+    }
+
+    /// Pull frames from the wrapped decoder until `n` samples per channel
+    /// are buffered, then emit them as a single `AudioFrame`. Emits a
+    /// shorter final frame once the decoder is exhausted with fewer than `n`
+    /// samples left, or `None` once nothing remains at all.
+    pub fn decode_exact(&mut self, n: usize) -> AudioResult<Option<AudioFrame>> {
+        while !self.exhausted {
+            if let Some((_, channels)) = self.format {
+                if self.buffers.samples_available() >= n * channels.count() as usize {
+                    break;
+                }
+            }
+
+            match self.decoder.decode_frame()? {
+                Some(frame) => {
+                    self.format.get_or_insert((frame.sample_rate(), frame.channels()));
+                    self.buffers.push(frame.samples().to_vec());
+                }
+                None => self.exhausted = true,
+            }
+        }
+
+        let (sample_rate, channels) = match self.format {
+            Some(format) => format,
+            None => return Ok(None),
+        };
+        let num_channels = channels.count() as usize;
+
+        let available = self.buffers.samples_available();
+        let wanted = (n * num_channels).min(available - available % num_channels);
+        if wanted == 0 {
+            return Ok(None);
+        }
+
+        let mut samples = vec![0.0; wanted];
+        self.buffers.consume_exact(&mut samples);
+
+        let frame = AudioFrame::new(samples, sample_rate, channels, self.next_frame_number)?;
+        self.next_frame_number += 1;
+        Ok(Some(frame))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_select_backend_dispatches_by_extension() {
+        assert_eq!(select_backend(Path::new("song.wav")), Backend::Wav);
+        assert_eq!(select_backend(Path::new("song.WAV")), Backend::Wav);
+        assert_eq!(select_backend(Path::new("song.mp3")), Backend::Mp3);
+        assert_eq!(select_backend(Path::new("song.MP3")), Backend::Mp3);
+        assert_eq!(select_backend(Path::new("song")), Backend::Symphonia);
+        assert_eq!(select_backend(Path::new("song.flac")), Backend::Symphonia);
+    }
+
+    /// Decoder stub that yields a fixed, pre-chunked sequence of frames -
+    /// standing in for a codec whose packet sizes don't line up with the
+    /// consumer's requested block size
+    struct MockDecoder {
+        chunks: VecDeque<Vec<f32>>,
+        sample_rate: u32,
+        channels: Channels,
+    }
+
+    impl Decoder for MockDecoder {
+        fn decode_frame(&mut self) -> AudioResult<Option<AudioFrame>> {
+            match self.chunks.pop_front() {
+                Some(samples) => Ok(Some(AudioFrame::new(samples, self.sample_rate, self.channels, 0)?)),
+                None => Ok(None),
+            }
+        }
+
+        fn is_finished(&self) -> bool {
+            self.chunks.is_empty()
+        }
+    }
+
+    #[test]
+    fn test_decode_exact_splits_variable_chunks_into_fixed_blocks() {
+        let decoder = MockDecoder {
+            chunks: VecDeque::from(vec![vec![1.0, 2.0, 3.0], vec![4.0], vec![5.0, 6.0, 7.0, 8.0]]),
+            sample_rate: 44100,
+            channels: Channels::Mono,
+        };
+        let mut exact = ExactDecoder::new(Box::new(decoder));
+
+        let first = exact.decode_exact(2).unwrap().unwrap();
+        assert_eq!(first.samples(), &[1.0, 2.0]);
+
+        let second = exact.decode_exact(2).unwrap().unwrap();
+        assert_eq!(second.samples(), &[3.0, 4.0]);
+
+        let third = exact.decode_exact(2).unwrap().unwrap();
+        assert_eq!(third.samples(), &[5.0, 6.0]);
+
+        let fourth = exact.decode_exact(2).unwrap().unwrap();
+        assert_eq!(fourth.samples(), &[7.0, 8.0]);
+
+        assert!(exact.decode_exact(2).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_decode_exact_emits_short_final_block() {
+        let decoder = MockDecoder {
+            chunks: VecDeque::from(vec![vec![1.0, 2.0, 3.0]]),
+            sample_rate: 44100,
+            channels: Channels::Mono,
+        };
+        let mut exact = ExactDecoder::new(Box::new(decoder));
+
+        let first = exact.decode_exact(2).unwrap().unwrap();
+        assert_eq!(first.samples(), &[1.0, 2.0]);
+
+        let last = exact.decode_exact(2).unwrap().unwrap();
+        assert_eq!(last.samples(), &[3.0]);
+
+        assert!(exact.decode_exact(2).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_decode_exact_on_empty_decoder_returns_none() {
+        let decoder = MockDecoder {
+            chunks: VecDeque::new(),
+            sample_rate: 44100,
+            channels: Channels::Stereo,
+        };
+        let mut exact = ExactDecoder::new(Box::new(decoder));
+        assert!(exact.decode_exact(4).unwrap().is_none());
+    }
 }
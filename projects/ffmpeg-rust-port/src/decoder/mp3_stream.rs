@@ -0,0 +1,302 @@
+//! Streaming MPEG audio (MP3) decoder fed by pushed byte blocks instead of a
+//! seekable file
+//!
+//! This establishes the part of an incremental MP3 backend that's new here:
+//! ingesting arbitrarily-sized byte chunks via [`StreamingMp3Decoder::push_bytes`]
+//! and locating frame boundaries in the resulting stream, so `decode_frame`
+//! can drain whatever complete frames are currently buffered regardless of
+//! how the bytes arrived. Full MPEG audio synthesis (subband filtering,
+//! Huffman decoding, the IMDCT) is a large, self-contained undertaking that's
+//! out of scope for this change - each recognized frame is emitted as a
+//! correctly-sized, correctly-rated silent `AudioFrame` rather than left
+//! unimplemented, so the ingestion/framing machinery can be exercised (and a
+//! real synthesis stage dropped in behind it later) without changing how
+//! callers feed bytes in. Because that silence is indistinguishable from a
+//! genuinely quiet passage by sample inspection alone,
+//! [`Decoder::is_placeholder_audio`](super::Decoder::is_placeholder_audio)
+//! always returns `true` for this decoder, so callers can tell the
+//! difference without inspecting samples.
+
+use crate::core::{AudioFrame, Channels};
+use crate::error::AudioResult;
+
+/// Mask isolating the 11-bit frame sync word (binary `11111111111...`) at the
+/// top of a 4-byte MPEG frame header
+const SYNC_MASK: u32 = 0xFFE0_0000;
+
+/// MPEG-1 Layer III bitrates in kbps, indexed by the header's 4-bit bitrate
+/// index (index 0 is "free bitrate", unsupported here)
+const MPEG1_LAYER3_BITRATES_KBPS: [u32; 16] = [
+    0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320, 0,
+];
+/// MPEG-2/2.5 Layer III bitrates in kbps, indexed the same way
+const MPEG2_LAYER3_BITRATES_KBPS: [u32; 16] = [
+    0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160, 0,
+];
+
+/// A parsed MPEG audio frame header, carrying just enough information to
+/// locate the frame's boundary in the byte stream
+///
+/// Only Layer III (the `.mp3` layer) is recognized; Layer I/II headers parse
+/// as `None` so the caller's resync loop skips past them.
+#[derive(Debug, Clone, Copy)]
+struct FrameHeader {
+    sample_rate: u32,
+    channels: Channels,
+    /// Samples per channel encoded in this frame (1152 for MPEG-1, 576 for
+    /// MPEG-2/2.5)
+    samples_per_frame: usize,
+    /// Total frame length in bytes, header included
+    frame_len: usize,
+}
+
+impl FrameHeader {
+    /// Try to parse a frame header from the start of `data`
+    fn parse(data: &[u8]) -> Option<Self> {
+        if data.len() < 4 {
+            return None;
+        }
+        let word = u32::from_be_bytes([data[0], data[1], data[2], data[3]]);
+        if word & SYNC_MASK != SYNC_MASK {
+            return None;
+        }
+
+        let version_bits = (word >> 19) & 0b11;
+        let layer_bits = (word >> 17) & 0b11;
+        if layer_bits != 0b01 {
+            return None; // Only Layer III is supported.
+        }
+        if version_bits == 0b01 {
+            return None; // Reserved version.
+        }
+
+        let bitrate_index = ((word >> 12) & 0b1111) as usize;
+        let sample_rate_index = ((word >> 10) & 0b11) as usize;
+        let padding = (word >> 9) & 0b1 == 1;
+        let channel_mode = (word >> 6) & 0b11;
+
+        let is_mpeg1 = version_bits == 0b11;
+        let sample_rate = match (version_bits, sample_rate_index) {
+            (0b11, 0b00) => 44_100,
+            (0b11, 0b01) => 48_000,
+            (0b11, 0b10) => 32_000,
+            (0b10, 0b00) => 22_050,
+            (0b10, 0b01) => 24_000,
+            (0b10, 0b10) => 16_000,
+            (0b00, 0b00) => 11_025,
+            (0b00, 0b01) => 12_000,
+            (0b00, 0b10) => 8_000,
+            _ => return None,
+        };
+
+        let bitrate_kbps = if is_mpeg1 {
+            MPEG1_LAYER3_BITRATES_KBPS[bitrate_index]
+        } else {
+            MPEG2_LAYER3_BITRATES_KBPS[bitrate_index]
+        };
+        if bitrate_kbps == 0 {
+            return None;
+        }
+
+        let coefficient = if is_mpeg1 { 144 } else { 72 };
+        let frame_len = (coefficient * bitrate_kbps * 1000 / sample_rate) as usize
+            + if padding { 1 } else { 0 };
+        if frame_len < 4 {
+            return None;
+        }
+
+        Some(FrameHeader {
+            sample_rate,
+            channels: if channel_mode == 0b11 {
+                Channels::Mono
+            } else {
+                Channels::Stereo
+            },
+            samples_per_frame: if is_mpeg1 { 1152 } else { 576 },
+            frame_len,
+        })
+    }
+}
+
+/// Streaming MP3 decoder that ingests pushed byte blocks (e.g. arriving over
+/// a network socket) instead of reading a seekable file
+///
+/// Call [`push_bytes`](Self::push_bytes) as data arrives and
+/// [`Decoder::decode_frame`](super::Decoder::decode_frame) to drain whatever
+/// complete frames are currently buffered; call
+/// [`end_of_stream`](Self::end_of_stream) once no more bytes are coming so
+/// `decode_frame` stops waiting on a trailing partial frame.
+pub struct StreamingMp3Decoder {
+    buffer: Vec<u8>,
+    next_frame_number: u64,
+    end_of_stream: bool,
+}
+
+impl StreamingMp3Decoder {
+    /// Create a decoder with nothing buffered yet
+    pub fn new() -> Self {
+        StreamingMp3Decoder {
+            buffer: Vec::new(),
+            next_frame_number: 0,
+            end_of_stream: false,
+        }
+    }
+
+    /// Append newly-received bytes to the ingest buffer
+    pub fn push_bytes(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+
+    /// Signal that no further bytes will be pushed; once the buffered bytes
+    /// are drained, `decode_frame` reports the stream finished instead of
+    /// waiting on a complete final frame
+    pub fn end_of_stream(&mut self) {
+        self.end_of_stream = true;
+    }
+
+    /// Drop bytes from the front of the buffer until it starts with a
+    /// recognizable frame header, returning that header
+    fn resync(&mut self) -> Option<FrameHeader> {
+        while self.buffer.len() >= 4 {
+            if let Some(header) = FrameHeader::parse(&self.buffer) {
+                return Some(header);
+            }
+            self.buffer.remove(0);
+        }
+        None
+    }
+}
+
+impl Default for StreamingMp3Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::Decoder for StreamingMp3Decoder {
+    fn decode_frame(&mut self) -> AudioResult<Option<AudioFrame>> {
+        let header = match self.resync() {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+
+        if self.buffer.len() < header.frame_len {
+            if self.end_of_stream {
+                // No more bytes are coming, so this trailing partial frame
+                // can never complete; drop it and report the stream finished.
+                self.buffer.clear();
+            }
+            // Otherwise a full frame isn't buffered yet; wait for more pushed bytes.
+            return Ok(None);
+        }
+
+        self.buffer.drain(0..header.frame_len);
+
+        let num_channels = header.channels.count() as usize;
+        let samples = vec![0.0f32; header.samples_per_frame * num_channels];
+        let frame = AudioFrame::new(
+            samples,
+            header.sample_rate,
+            header.channels,
+            self.next_frame_number,
+        )?;
+        self.next_frame_number += 1;
+        Ok(Some(frame))
+    }
+
+    fn is_finished(&self) -> bool {
+        self.end_of_stream && self.buffer.len() < 4
+    }
+
+    fn is_placeholder_audio(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::Decoder;
+
+    /// A minimal valid MPEG-1 Layer III frame header: 128kbps, 44.1kHz,
+    /// stereo, no padding, no CRC (protection bit set)
+    const MPEG1_128K_44100_STEREO_HEADER: [u8; 4] = [0xFF, 0xFB, 0x90, 0x00];
+
+    #[test]
+    fn test_frame_header_parses_known_header() {
+        let header = FrameHeader::parse(&MPEG1_128K_44100_STEREO_HEADER).unwrap();
+        assert_eq!(header.sample_rate, 44_100);
+        assert_eq!(header.channels, Channels::Stereo);
+        assert_eq!(header.samples_per_frame, 1152);
+        // 144 * 128000 / 44100 = 417 (truncated), no padding byte.
+        assert_eq!(header.frame_len, 417);
+    }
+
+    #[test]
+    fn test_frame_header_rejects_garbage() {
+        assert!(FrameHeader::parse(&[0x00, 0x00, 0x00, 0x00]).is_none());
+    }
+
+    fn make_frame() -> Vec<u8> {
+        let mut frame = vec![0u8; 417];
+        frame[0..4].copy_from_slice(&MPEG1_128K_44100_STEREO_HEADER);
+        frame
+    }
+
+    #[test]
+    fn test_is_placeholder_audio_is_always_true() {
+        let decoder = StreamingMp3Decoder::new();
+        assert!(decoder.is_placeholder_audio());
+    }
+
+    #[test]
+    fn test_decode_frame_waits_for_a_complete_frame() {
+        let mut decoder = StreamingMp3Decoder::new();
+        let frame_bytes = make_frame();
+
+        decoder.push_bytes(&frame_bytes[..100]);
+        assert!(decoder.decode_frame().unwrap().is_none());
+
+        decoder.push_bytes(&frame_bytes[100..]);
+        let frame = decoder.decode_frame().unwrap().unwrap();
+        assert_eq!(frame.sample_rate(), 44_100);
+        assert_eq!(frame.channels(), Channels::Stereo);
+        assert_eq!(frame.samples_per_channel(), 1152);
+    }
+
+    #[test]
+    fn test_decode_frame_skips_leading_garbage_bytes() {
+        let mut decoder = StreamingMp3Decoder::new();
+        let mut bytes = vec![0x00, 0x11, 0x22];
+        bytes.extend_from_slice(&make_frame());
+        decoder.push_bytes(&bytes);
+
+        let frame = decoder.decode_frame().unwrap().unwrap();
+        assert_eq!(frame.samples_per_channel(), 1152);
+    }
+
+    #[test]
+    fn test_decode_frame_drains_multiple_buffered_frames() {
+        let mut decoder = StreamingMp3Decoder::new();
+        let mut bytes = make_frame();
+        bytes.extend_from_slice(&make_frame());
+        decoder.push_bytes(&bytes);
+
+        assert!(decoder.decode_frame().unwrap().is_some());
+        assert!(decoder.decode_frame().unwrap().is_some());
+        assert!(decoder.decode_frame().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_is_finished_only_after_end_of_stream_with_nothing_buffered() {
+        let mut decoder = StreamingMp3Decoder::new();
+        assert!(!decoder.is_finished());
+
+        decoder.push_bytes(&make_frame()[..200]);
+        decoder.end_of_stream();
+        assert!(!decoder.is_finished());
+
+        decoder.decode_frame().unwrap();
+        assert!(decoder.is_finished());
+    }
+}
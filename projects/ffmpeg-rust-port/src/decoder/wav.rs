@@ -0,0 +1,202 @@
+use crate::core::{AudioFrame, Channels};
+use crate::error::{AudioError, AudioResult};
+use hound::{SampleFormat, WavReader};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::time::Duration;
+
+/// Number of frames (per channel) decoded per call to `decode_frame`
+const FRAMES_PER_BLOCK: usize = 4096;
+
+/// Native hound-based WAV decoder
+///
+/// Avoids the Symphonia dependency entirely for the very common WAV-in case,
+/// and serves as a round-trip-correct reference decoder to validate the
+/// Symphonia path against.
+pub struct WavDecoder {
+    reader: WavReader<BufReader<File>>,
+    sample_rate: u32,
+    channels: Channels,
+    sample_format: SampleFormat,
+    bits_per_sample: u16,
+    frame_count: u64,
+    finished: bool,
+    /// Total frames (samples per channel) in the file, from the WAV header
+    total_frames: u64,
+    /// Frames (samples per channel) returned via `decode_frame` so far
+    consumed_frames: u64,
+}
+
+impl WavDecoder {
+    /// Create decoder from file path
+    pub fn from_file<P: AsRef<Path>>(path: P) -> AudioResult<Self> {
+        let reader = WavReader::open(path)?;
+        let spec = reader.spec();
+
+        let channels = Channels::from_count(spec.channels as u32)?;
+        let total_frames = reader.duration() as u64;
+
+        Ok(WavDecoder {
+            reader,
+            sample_rate: spec.sample_rate,
+            channels,
+            sample_format: spec.sample_format,
+            bits_per_sample: spec.bits_per_sample,
+            frame_count: 0,
+            finished: false,
+            total_frames,
+            consumed_frames: 0,
+        })
+    }
+
+    /// Get sample rate
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Get channels
+    pub fn channels(&self) -> Channels {
+        self.channels
+    }
+
+    /// Convert an integer PCM sample to `f32` in `[-1.0, 1.0]` based on the
+    /// source bit depth
+    ///
+    /// Takes `bits_per_sample` by value rather than `&self` so callers can
+    /// hold it in a local while a `self.reader.samples()` iterator still
+    /// mutably borrows `self`.
+    fn int_to_f32(bits_per_sample: u16, sample: i32) -> f32 {
+        let full_scale = (1i64 << (bits_per_sample - 1)) as f32;
+        (sample as f32 / full_scale).clamp(-1.0, 1.0)
+    }
+}
+
+impl super::Decoder for WavDecoder {
+    fn decode_frame(&mut self) -> AudioResult<Option<AudioFrame>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let num_channels = self.channels.count() as usize;
+        let samples_to_read = FRAMES_PER_BLOCK * num_channels;
+        let mut samples = Vec::with_capacity(samples_to_read);
+
+        match self.sample_format {
+            SampleFormat::Float => {
+                let mut iter = self.reader.samples::<f32>();
+                for _ in 0..samples_to_read {
+                    match iter.next() {
+                        Some(sample) => samples.push(sample?),
+                        None => break,
+                    }
+                }
+            }
+            SampleFormat::Int => {
+                let bits_per_sample = self.bits_per_sample;
+                let mut iter = self.reader.samples::<i32>();
+                for _ in 0..samples_to_read {
+                    match iter.next() {
+                        Some(sample) => samples.push(Self::int_to_f32(bits_per_sample, sample?)),
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        if samples.is_empty() {
+            self.finished = true;
+            return Ok(None);
+        }
+        if samples.len() % num_channels != 0 {
+            // Short final read left a partial sample group; drop the remainder
+            // rather than fail AudioFrame::new's divisibility check.
+            samples.truncate(samples.len() - samples.len() % num_channels);
+        }
+        if samples.len() < samples_to_read {
+            self.finished = true;
+        }
+
+        self.consumed_frames += (samples.len() / num_channels) as u64;
+        let frame = AudioFrame::new(samples, self.sample_rate, self.channels, self.frame_count)?;
+        self.frame_count += 1;
+
+        Ok(Some(frame))
+    }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn total_duration(&self) -> AudioResult<Duration> {
+        Ok(Duration::from_secs_f64(
+            self.total_frames as f64 / self.sample_rate as f64,
+        ))
+    }
+
+    fn samples_remaining(&self) -> AudioResult<u64> {
+        Ok(self.total_frames.saturating_sub(self.consumed_frames))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decoder::Decoder;
+    use crate::encoder::{Encoder, WavEncoder};
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_invalid_file() {
+        let result = WavDecoder::from_file("/nonexistent/file.wav");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_round_trip_float_wav() {
+        let temp_file = NamedTempFile::new().unwrap().into_temp_path();
+        let path = temp_file.with_extension("wav");
+
+        let samples: Vec<f32> = (0..1000).map(|i| (i as f32 * 0.01).sin() * 0.5).collect();
+        let frame = AudioFrame::new(samples.clone(), 44100, Channels::Mono, 0).unwrap();
+
+        let mut encoder = WavEncoder::new(&path, 44100, Channels::Mono).unwrap();
+        encoder.encode(&frame).unwrap();
+        encoder.finalize().unwrap();
+
+        let mut decoder = WavDecoder::from_file(&path).unwrap();
+        let mut decoded = Vec::new();
+        while let Some(frame) = decoder.decode_frame().unwrap() {
+            decoded.extend_from_slice(frame.samples());
+        }
+
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(decoded.len(), samples.len());
+        for (a, b) in decoded.iter().zip(samples.iter()) {
+            assert!((a - b).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_total_duration_and_samples_remaining() {
+        let temp_file = NamedTempFile::new().unwrap().into_temp_path();
+        let path = temp_file.with_extension("wav");
+
+        let samples: Vec<f32> = vec![0.0; 44100];
+        let frame = AudioFrame::new(samples, 44100, Channels::Mono, 0).unwrap();
+
+        let mut encoder = WavEncoder::new(&path, 44100, Channels::Mono).unwrap();
+        encoder.encode(&frame).unwrap();
+        encoder.finalize().unwrap();
+
+        let mut decoder = WavDecoder::from_file(&path).unwrap();
+        assert_eq!(decoder.total_duration().unwrap(), std::time::Duration::from_secs(1));
+        assert_eq!(decoder.samples_remaining().unwrap(), 44100);
+
+        decoder.decode_frame().unwrap();
+        assert_eq!(decoder.samples_remaining().unwrap(), 44100 - FRAMES_PER_BLOCK as u64);
+
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -2,7 +2,7 @@
 
 pub mod wav;
 
-pub use wav::WavEncoder;
+pub use wav::{SampleFormat, WavEncoder};
 
 use crate::core::AudioFrame;
 use crate::error::AudioResult;
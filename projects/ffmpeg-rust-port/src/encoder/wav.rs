@@ -3,25 +3,67 @@ use crate::error::{AudioError, AudioResult};
 use hound::{WavWriter, WavSpec};
 use std::path::Path;
 
+/// Output sample format for [`WavEncoder`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleFormat {
+    /// 32-bit IEEE float (the original default)
+    F32,
+    /// 16-bit signed PCM
+    S16,
+    /// 24-bit signed PCM (stored as 3-byte little-endian samples)
+    S24,
+    /// 32-bit signed PCM
+    S32,
+}
+
+impl SampleFormat {
+    fn bits_per_sample(&self) -> u16 {
+        match self {
+            SampleFormat::F32 => 32,
+            SampleFormat::S16 => 16,
+            SampleFormat::S24 => 24,
+            SampleFormat::S32 => 32,
+        }
+    }
+
+    fn hound_format(&self) -> hound::SampleFormat {
+        match self {
+            SampleFormat::F32 => hound::SampleFormat::Float,
+            SampleFormat::S16 | SampleFormat::S24 | SampleFormat::S32 => hound::SampleFormat::Int,
+        }
+    }
+}
+
 /// WAV audio encoder
 pub struct WavEncoder {
     writer: Option<WavWriter<std::io::BufWriter<std::fs::File>>>,
     sample_rate: u32,
     channels: Channels,
+    format: SampleFormat,
 }
 
 impl WavEncoder {
-    /// Create a new WAV encoder to file
+    /// Create a new 32-bit float WAV encoder to file
     pub fn new<P: AsRef<Path>>(
         path: P,
         sample_rate: u32,
         channels: Channels,
+    ) -> AudioResult<Self> {
+        Self::with_format(path, sample_rate, channels, SampleFormat::F32)
+    }
+
+    /// Create a new WAV encoder targeting a specific sample format
+    pub fn with_format<P: AsRef<Path>>(
+        path: P,
+        sample_rate: u32,
+        channels: Channels,
+        format: SampleFormat,
     ) -> AudioResult<Self> {
         let spec = WavSpec {
             channels: channels.count() as u16,
             sample_rate,
-            bits_per_sample: 32,
-            sample_format: hound::SampleFormat::Float,
+            bits_per_sample: format.bits_per_sample(),
+            sample_format: format.hound_format(),
         };
 
         let writer = WavWriter::create(path, spec)
@@ -31,6 +73,7 @@ impl WavEncoder {
             writer: Some(writer),
             sample_rate,
             channels,
+            format,
         })
     }
 
@@ -44,10 +87,21 @@ impl WavEncoder {
         self.channels
     }
 
+    /// Get the output sample format
+    pub fn format(&self) -> SampleFormat {
+        self.format
+    }
+
     /// Get the number of frames written
     pub fn frames_written(&self) -> u32 {
         self.writer.as_ref().map(|w| w.len()).unwrap_or(0)
     }
+
+    /// Quantize a `[-1.0, 1.0]` sample to the integer range for this format
+    fn quantize(sample: f32, max: i64) -> i32 {
+        let clamped = sample.clamp(-1.0, 1.0) as f64;
+        (clamped * max as f64).round() as i32
+    }
 }
 
 impl super::Encoder for WavEncoder {
@@ -68,11 +122,40 @@ impl super::Encoder for WavEncoder {
         let writer = self.writer.as_mut()
             .ok_or_else(|| AudioError::ProcessingError("Encoder already finalized".to_string()))?;
 
-        // Write each sample to the WAV file
-        for &sample in frame.samples() {
-            writer
-                .write_sample(sample)
-                .map_err(|e| AudioError::EncodeError(e.to_string()))?;
+        match self.format {
+            SampleFormat::F32 => {
+                for &sample in frame.samples() {
+                    writer
+                        .write_sample(sample)
+                        .map_err(|e| AudioError::EncodeError(e.to_string()))?;
+                }
+            }
+            SampleFormat::S16 => {
+                for &sample in frame.samples() {
+                    let quantized = Self::quantize(sample, i16::MAX as i64) as i16;
+                    writer
+                        .write_sample(quantized)
+                        .map_err(|e| AudioError::EncodeError(e.to_string()))?;
+                }
+            }
+            SampleFormat::S24 => {
+                // hound stores 24-bit samples as i32 with the spec's bits_per_sample
+                // telling it to truncate to 3 bytes on write.
+                for &sample in frame.samples() {
+                    let quantized = Self::quantize(sample, (1i64 << 23) - 1) as i32;
+                    writer
+                        .write_sample(quantized)
+                        .map_err(|e| AudioError::EncodeError(e.to_string()))?;
+                }
+            }
+            SampleFormat::S32 => {
+                for &sample in frame.samples() {
+                    let quantized = Self::quantize(sample, i32::MAX as i64);
+                    writer
+                        .write_sample(quantized)
+                        .map_err(|e| AudioError::EncodeError(e.to_string()))?;
+                }
+            }
         }
 
         Ok(())
@@ -146,4 +229,29 @@ mod tests {
         let result = encoder.encode(&frame);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_wav_encoder_s16_round_trip() {
+        let temp_file = NamedTempFile::new().unwrap().into_temp_path();
+        let path = temp_file.with_extension("wav");
+
+        let samples = vec![0.0, 0.5, -0.5, 1.0, -1.0];
+        let frame = AudioFrame::new(samples.clone(), 44100, Channels::Mono, 0).unwrap();
+
+        let mut encoder =
+            WavEncoder::with_format(&path, 44100, Channels::Mono, SampleFormat::S16).unwrap();
+        encoder.encode(&frame).unwrap();
+        encoder.finalize().unwrap();
+
+        let mut reader = hound::WavReader::open(&path).unwrap();
+        assert_eq!(reader.spec().bits_per_sample, 16);
+        let decoded: Vec<i16> = reader.samples::<i16>().map(|s| s.unwrap()).collect();
+        std::fs::remove_file(&path).ok();
+
+        let expected: Vec<i16> = samples
+            .iter()
+            .map(|&s| WavEncoder::quantize(s, i16::MAX as i64) as i16)
+            .collect();
+        assert_eq!(decoded, expected);
+    }
 }
@@ -62,6 +62,10 @@ pub enum AudioError {
     /// Audio processing error
     #[error("Processing error: {0}")]
     ProcessingError(String),
+
+    /// Requested operation is not supported by this implementation
+    #[error("Unsupported operation: {0}")]
+    Unsupported(String),
 }
 
 impl From<symphonia::core::errors::Error> for AudioError {
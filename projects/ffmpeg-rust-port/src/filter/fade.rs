@@ -0,0 +1,253 @@
+use crate::core::AudioFrame;
+use crate::error::{AudioError, AudioResult};
+use std::time::Duration;
+
+/// Gain envelope curve shape used by [`Fade`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FadeCurve {
+    /// Constant-rate ramp
+    Linear,
+    /// Constant-rate ramp in decibels (audibly more natural for fade-outs)
+    Exponential,
+    /// Smooth S-curve: `0.5 * (1 - cos(pi * t))`
+    RaisedCosine,
+}
+
+impl FadeCurve {
+    /// Evaluate the curve at `t` in `[0.0, 1.0]`, returning a linear gain in
+    /// `[0.0, 1.0]`
+    fn gain(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            FadeCurve::Linear => t,
+            FadeCurve::Exponential => {
+                const FLOOR_DB: f32 = -60.0;
+                let db = FLOOR_DB * (1.0 - t);
+                10f32.powf(db / 20.0)
+            }
+            FadeCurve::RaisedCosine => 0.5 * (1.0 - (std::f32::consts::PI * t).cos()),
+        }
+    }
+}
+
+/// Where a fade window begins, relative to the start or end of the stream
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FadePosition {
+    /// This long after the start of the stream
+    FromStart(Duration),
+    /// This long before the end of the stream (requires a known total
+    /// duration, see [`Fade::with_total_duration`])
+    FromEnd(Duration),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FadeWindow {
+    start: FadePosition,
+    duration: Duration,
+}
+
+/// Fade-in/fade-out gain-automation filter (ffmpeg's `afade` equivalent)
+///
+/// Tracks absolute sample position across successive `process()` calls so the
+/// envelope stays continuous at frame boundaries.
+#[derive(Debug, Clone)]
+pub struct Fade {
+    curve: FadeCurve,
+    fade_in: Option<FadeWindow>,
+    fade_out: Option<FadeWindow>,
+    total_duration: Option<Duration>,
+    sample_rate: Option<u32>,
+    /// Absolute sample position (per channel) of the next sample to process
+    position: u64,
+}
+
+impl Fade {
+    /// Create a fade filter with no fades configured (acts as a no-op until
+    /// `with_fade_in`/`with_fade_out` are set)
+    pub fn new() -> Self {
+        Fade {
+            curve: FadeCurve::Linear,
+            fade_in: None,
+            fade_out: None,
+            total_duration: None,
+            sample_rate: None,
+            position: 0,
+        }
+    }
+
+    /// Set the envelope curve shape (applies to both fade-in and fade-out)
+    pub fn with_curve(mut self, curve: FadeCurve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Configure a fade-in starting at `start` and ramping from silence to
+    /// full volume over `duration`
+    pub fn with_fade_in(mut self, start: FadePosition, duration: Duration) -> Self {
+        self.fade_in = Some(FadeWindow { start, duration });
+        self
+    }
+
+    /// Configure a fade-out starting at `start` and ramping from full volume
+    /// to silence over `duration`
+    pub fn with_fade_out(mut self, start: FadePosition, duration: Duration) -> Self {
+        self.fade_out = Some(FadeWindow { start, duration });
+        self
+    }
+
+    /// Provide the total stream duration, required when a fade window is
+    /// positioned with [`FadePosition::FromEnd`]
+    pub fn with_total_duration(mut self, total: Duration) -> Self {
+        self.total_duration = Some(total);
+        self
+    }
+
+    fn resolve_start(&self, position: FadePosition) -> AudioResult<Duration> {
+        match position {
+            FadePosition::FromStart(d) => Ok(d),
+            FadePosition::FromEnd(d) => {
+                let total = self.total_duration.ok_or_else(|| {
+                    AudioError::ConfigError(
+                        "Fade::with_total_duration is required to use FadePosition::FromEnd"
+                            .to_string(),
+                    )
+                })?;
+                Ok(total.saturating_sub(d))
+            }
+        }
+    }
+
+    /// Gain contribution of a single fade window at absolute time `t`,
+    /// ramping `from -> to` across `[start, start + duration)`
+    fn window_gain(
+        &self,
+        window: &FadeWindow,
+        t: Duration,
+        ramp_up: bool,
+    ) -> AudioResult<f32> {
+        let start = self.resolve_start(window.start)?;
+        let end = start + window.duration;
+
+        Ok(if t < start {
+            if ramp_up {
+                0.0
+            } else {
+                1.0
+            }
+        } else if t >= end {
+            if ramp_up {
+                1.0
+            } else {
+                0.0
+            }
+        } else {
+            let progress =
+                (t.as_secs_f64() - start.as_secs_f64()) / window.duration.as_secs_f64();
+            let gain = self.curve.gain(progress as f32);
+            if ramp_up {
+                gain
+            } else {
+                1.0 - gain
+            }
+        })
+    }
+
+    fn gain_at(&self, t: Duration) -> AudioResult<f32> {
+        let mut gain = 1.0;
+        if let Some(window) = &self.fade_in {
+            gain *= self.window_gain(window, t, true)?;
+        }
+        if let Some(window) = &self.fade_out {
+            gain *= self.window_gain(window, t, false)?;
+        }
+        Ok(gain)
+    }
+}
+
+impl Default for Fade {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::Filter for Fade {
+    fn process(&mut self, frame: &AudioFrame) -> AudioResult<AudioFrame> {
+        let sample_rate = *self.sample_rate.get_or_insert(frame.sample_rate());
+        let num_channels = frame.channels().count() as usize;
+        let samples = frame.samples();
+
+        let mut output = Vec::with_capacity(samples.len());
+        for (i, group) in samples.chunks(num_channels).enumerate() {
+            let abs_sample = self.position + i as u64;
+            let t = Duration::from_secs_f64(abs_sample as f64 / sample_rate as f64);
+            let gain = self.gain_at(t)?;
+            for &sample in group {
+                output.push((sample * gain).clamp(-1.0, 1.0));
+            }
+        }
+
+        self.position += (samples.len() / num_channels.max(1)) as u64;
+
+        Ok(AudioFrame::new(
+            output,
+            frame.sample_rate(),
+            frame.channels(),
+            frame.frame_number(),
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Channels;
+    use crate::filter::Filter;
+
+    #[test]
+    fn test_fade_in_ramps_from_silence_to_full() {
+        let mut fade = Fade::new().with_fade_in(FadePosition::FromStart(Duration::ZERO), Duration::from_secs(1));
+
+        let samples = vec![1.0; 44100];
+        let frame = AudioFrame::new(samples, 44100, Channels::Mono, 0).unwrap();
+
+        let result = fade.process(&frame).unwrap();
+        let out = result.samples();
+
+        assert!(out[0].abs() < 0.01);
+        assert!((out[out.len() - 1] - 1.0).abs() < 0.01);
+        // Monotonically increasing envelope
+        assert!(out[100] < out[40000]);
+    }
+
+    #[test]
+    fn test_fade_out_ramps_to_silence_before_end() {
+        let total = Duration::from_secs(2);
+        let mut fade = Fade::new()
+            .with_total_duration(total)
+            .with_fade_out(FadePosition::FromEnd(Duration::from_secs(1)), Duration::from_secs(1));
+
+        let samples = vec![1.0; 88200];
+        let frame = AudioFrame::new(samples, 44100, Channels::Mono, 0).unwrap();
+
+        let result = fade.process(&frame).unwrap();
+        let out = result.samples();
+
+        assert!((out[0] - 1.0).abs() < 0.01);
+        assert!(out[out.len() - 1].abs() < 0.01);
+    }
+
+    #[test]
+    fn test_fade_is_continuous_across_frames() {
+        let mut fade = Fade::new().with_fade_in(FadePosition::FromStart(Duration::ZERO), Duration::from_secs(2));
+
+        let frame1 = AudioFrame::new(vec![1.0; 44100], 44100, Channels::Mono, 0).unwrap();
+        let frame2 = AudioFrame::new(vec![1.0; 44100], 44100, Channels::Mono, 1).unwrap();
+
+        let out1 = fade.process(&frame1).unwrap();
+        let out2 = fade.process(&frame2).unwrap();
+
+        // Gain at the start of frame2 should continue smoothly from the end of frame1.
+        let boundary_gap = (out2.samples()[0] - out1.samples()[out1.samples().len() - 1]).abs();
+        assert!(boundary_gap < 0.01);
+    }
+}
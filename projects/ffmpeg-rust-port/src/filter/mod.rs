@@ -3,10 +3,12 @@
 pub mod resample;
 pub mod remix;
 pub mod normalize;
+pub mod fade;
 
-pub use resample::Resample;
+pub use resample::{InterpolationMode, Resample};
 pub use remix::Remix;
 pub use normalize::Normalize;
+pub use fade::{Fade, FadeCurve, FadePosition};
 
 use crate::core::AudioFrame;
 use crate::error::AudioResult;
@@ -1,6 +1,236 @@
 use crate::core::AudioFrame;
 use crate::error::{AudioError, AudioResult};
 
+/// Length of a gating sub-block in milliseconds. Blocks are formed from 4
+/// consecutive sub-blocks (400ms), advancing one sub-block at a time, which
+/// gives the 75% overlap required by ITU-R BS.1770 / EBU R128.
+const SUBBLOCK_MS: u32 = 100;
+/// Number of sub-blocks making up one gating block
+const SUBBLOCKS_PER_BLOCK: usize = 4;
+/// Absolute gating threshold in LUFS
+const ABSOLUTE_GATE_LUFS: f64 = -70.0;
+/// Relative gate offset below the ungated mean, in LU
+const RELATIVE_GATE_OFFSET_LU: f64 = -10.0;
+
+/// Second-order IIR (biquad) section used to build the K-weighting filter
+#[derive(Clone, Debug, Default)]
+struct Biquad {
+    b0: f64,
+    b1: f64,
+    b2: f64,
+    a1: f64,
+    a2: f64,
+    x1: f64,
+    x2: f64,
+    y1: f64,
+    y2: f64,
+}
+
+impl Biquad {
+    fn process(&mut self, x0: f64) -> f64 {
+        let y0 = self.b0 * x0 + self.b1 * self.x1 + self.b2 * self.x2
+            - self.a1 * self.y1
+            - self.a2 * self.y2;
+        self.x2 = self.x1;
+        self.x1 = x0;
+        self.y2 = self.y1;
+        self.y1 = y0;
+        y0
+    }
+
+    /// ITU-R BS.1770 stage 1: high-shelf "pre-filter", generalized from the
+    /// 48kHz reference coefficients to an arbitrary sample rate via the
+    /// standard bilinear-transform formulas (as used by libebur128).
+    fn pre_filter(sample_rate: u32) -> Self {
+        let fs = sample_rate as f64;
+        let f0 = 1681.974_450_955_533_0_f64;
+        let g = 3.999_843_853_973_347_0_f64;
+        let q = 0.707_175_236_955_419_6_f64;
+
+        let k = (std::f64::consts::PI * f0 / fs).tan();
+        let vh = 10f64.powf(g / 20.0);
+        let vb = vh.powf(0.499_666_774_154_541_6);
+
+        let a0 = 1.0 + k / q + k * k;
+        Biquad {
+            b0: (vh + vb * k / q + k * k) / a0,
+            b1: 2.0 * (k * k - vh) / a0,
+            b2: (vh - vb * k / q + k * k) / a0,
+            a1: 2.0 * (k * k - 1.0) / a0,
+            a2: (1.0 - k / q + k * k) / a0,
+            ..Default::default()
+        }
+    }
+
+    /// ITU-R BS.1770 stage 2: RLB-weighting high-pass filter
+    fn rlb_filter(sample_rate: u32) -> Self {
+        let fs = sample_rate as f64;
+        let f0 = 38.135_470_876_024_44_f64;
+        let q = 0.500_327_037_323_877_3_f64;
+
+        let k = (std::f64::consts::PI * f0 / fs).tan();
+        let a0 = 1.0 + k / q + k * k;
+        Biquad {
+            b0: 1.0 / a0,
+            b1: -2.0 / a0,
+            b2: 1.0 / a0,
+            a1: 2.0 * (k * k - 1.0) / a0,
+            a2: (1.0 - k / q + k * k) / a0,
+            ..Default::default()
+        }
+    }
+}
+
+/// Per-channel K-weighting state plus the running sub-block accumulator
+#[derive(Clone, Debug)]
+struct ChannelState {
+    pre_filter: Biquad,
+    rlb_filter: Biquad,
+    /// Channel weight per ITU-R BS.1770 (1.0 for L/R/C, 1.41 for surround)
+    weight: f64,
+    subblock_sum_sq: f64,
+    subblock_samples: usize,
+}
+
+/// EBU R128 / ITU-R BS.1770 integrated-loudness analyzer and gain applier.
+///
+/// This is inherently a two-pass algorithm: the full signal must be measured
+/// before the integrated loudness (and therefore the gain) is known. Drive it
+/// by calling [`Filter::process`] for every frame during the analysis pass
+/// (it passes samples through unchanged while measuring), then
+/// [`Filter::flush`] once the stream is exhausted to compute the gain from
+/// the accumulated blocks. A second pass of `process` calls (e.g. after
+/// [`crate::decoder::Decoder::reset`]) then applies that gain.
+#[derive(Clone, Debug)]
+struct EbuR128State {
+    target_lufs: f32,
+    sample_rate: u32,
+    subblock_len: usize,
+    channels: Vec<ChannelState>,
+    /// Weighted mean-square energy of completed sub-blocks, most recent last
+    subblock_energies: Vec<f64>,
+    /// Weighted mean-square energy of completed 400ms blocks
+    blocks: Vec<f64>,
+    /// Gain computed by `flush`; `None` while still analyzing
+    gain: Option<f32>,
+}
+
+impl EbuR128State {
+    fn channel_weight(index: usize, total: usize) -> f64 {
+        // L/R (or mono) and center get unity weight; anything beyond stereo
+        // is treated as a surround channel per BS.1770's 1.41 (~sqrt(2))
+        // weighting. Only 5.1 and 7.1 have a center, at index 2 (FL, FR, FC, ...).
+        let is_center = index == 2 && (total == 6 || total == 8);
+        if total <= 2 || index < 2 || is_center {
+            1.0
+        } else {
+            1.41
+        }
+    }
+
+    fn ensure_initialized(&mut self, frame: &AudioFrame) {
+        if !self.channels.is_empty() {
+            return;
+        }
+
+        let num_channels = frame.channels().count() as usize;
+        self.sample_rate = frame.sample_rate();
+        self.subblock_len = (self.sample_rate as u64 * SUBBLOCK_MS as u64 / 1000) as usize;
+        self.channels = (0..num_channels)
+            .map(|i| ChannelState {
+                pre_filter: Biquad::pre_filter(self.sample_rate),
+                rlb_filter: Biquad::rlb_filter(self.sample_rate),
+                weight: Self::channel_weight(i, num_channels),
+                subblock_sum_sq: 0.0,
+                subblock_samples: 0,
+            })
+            .collect();
+    }
+
+    /// Feed interleaved samples through the K-weighting filters and
+    /// accumulate completed 100ms sub-blocks into 400ms gating blocks.
+    fn analyze(&mut self, frame: &AudioFrame) {
+        self.ensure_initialized(frame);
+
+        let num_channels = self.channels.len();
+        let samples = frame.samples();
+
+        for group in samples.chunks(num_channels) {
+            for (ch, &sample) in self.channels.iter_mut().zip(group.iter()) {
+                let filtered = ch.rlb_filter.process(ch.pre_filter.process(sample as f64));
+                ch.subblock_sum_sq += filtered * filtered;
+            }
+            self.channels[0].subblock_samples += 1;
+
+            if self.channels[0].subblock_samples >= self.subblock_len {
+                let weighted_energy: f64 = self
+                    .channels
+                    .iter_mut()
+                    .map(|ch| {
+                        let energy = ch.weight * ch.subblock_sum_sq / self.subblock_len as f64;
+                        ch.subblock_sum_sq = 0.0;
+                        ch.subblock_samples = 0;
+                        energy
+                    })
+                    .sum();
+                self.subblock_energies.push(weighted_energy);
+
+                if self.subblock_energies.len() >= SUBBLOCKS_PER_BLOCK {
+                    let start = self.subblock_energies.len() - SUBBLOCKS_PER_BLOCK;
+                    let block_energy: f64 = self.subblock_energies[start..].iter().sum::<f64>()
+                        / SUBBLOCKS_PER_BLOCK as f64;
+                    self.blocks.push(block_energy);
+                }
+            }
+        }
+    }
+
+    fn loudness_of(energy: f64) -> f64 {
+        -0.691 + 10.0 * energy.log10()
+    }
+
+    /// Run the two-stage BS.1770 gate and compute the integrated loudness and
+    /// the gain required to reach `target_lufs`.
+    fn compute_gain(&mut self) -> f32 {
+        if self.blocks.is_empty() {
+            return 1.0;
+        }
+
+        let absolute_gated: Vec<f64> = self
+            .blocks
+            .iter()
+            .copied()
+            .filter(|&e| Self::loudness_of(e) >= ABSOLUTE_GATE_LUFS)
+            .collect();
+
+        if absolute_gated.is_empty() {
+            return 1.0;
+        }
+
+        let relative_mean =
+            absolute_gated.iter().sum::<f64>() / absolute_gated.len() as f64;
+        let relative_threshold =
+            Self::loudness_of(relative_mean) + RELATIVE_GATE_OFFSET_LU;
+
+        let relative_gated: Vec<f64> = absolute_gated
+            .iter()
+            .copied()
+            .filter(|&e| Self::loudness_of(e) >= relative_threshold)
+            .collect();
+
+        let gated = if relative_gated.is_empty() {
+            &absolute_gated
+        } else {
+            &relative_gated
+        };
+
+        let integrated_mean = gated.iter().sum::<f64>() / gated.len() as f64;
+        let integrated_lufs = Self::loudness_of(integrated_mean);
+
+        10f64.powf((self.target_lufs as f64 - integrated_lufs) / 20.0) as f32
+    }
+}
+
 /// Audio normalization filter - adjusts volume levels
 #[derive(Clone, Debug)]
 pub struct Normalize {
@@ -8,6 +238,8 @@ pub struct Normalize {
     target_peak: f32,
     /// Whether to use loudness normalization (true) or peak normalization (false)
     use_loudness: bool,
+    /// EBU R128 state, present only when constructed via `ebu_r128`
+    ebu: Option<EbuR128State>,
 }
 
 impl Normalize {
@@ -23,6 +255,7 @@ impl Normalize {
         Ok(Normalize {
             target_peak,
             use_loudness: false,
+            ebu: None,
         })
     }
 
@@ -39,6 +272,37 @@ impl Normalize {
         Ok(Normalize {
             target_peak: target_loudness,
             use_loudness: true,
+            ebu: None,
+        })
+    }
+
+    /// Create an EBU R128 / ITU-R BS.1770 integrated-loudness normalizer
+    /// targeting `target_lufs` (e.g. -16.0 for streaming delivery).
+    ///
+    /// This is a two-pass filter: call [`Filter::process`] once per frame to
+    /// measure the signal (samples pass through unchanged), then
+    /// [`Filter::flush`] to compute the gain from the accumulated blocks.
+    /// Processing the stream a second time then applies that gain.
+    pub fn ebu_r128(target_lufs: f32) -> AudioResult<Self> {
+        if target_lufs >= 0.0 {
+            return Err(AudioError::ConfigError(format!(
+                "Target LUFS must be negative, got {}",
+                target_lufs
+            )));
+        }
+
+        Ok(Normalize {
+            target_peak: 0.0,
+            use_loudness: false,
+            ebu: Some(EbuR128State {
+                target_lufs,
+                sample_rate: 0,
+                subblock_len: 0,
+                channels: Vec::new(),
+                subblock_energies: Vec::new(),
+                blocks: Vec::new(),
+                gain: None,
+            }),
         })
     }
 
@@ -68,6 +332,30 @@ impl Normalize {
 
 impl super::Filter for Normalize {
     fn process(&mut self, frame: &AudioFrame) -> AudioResult<AudioFrame> {
+        if let Some(ebu) = self.ebu.as_mut() {
+            return match ebu.gain {
+                // Second pass: the gain has been computed, apply it.
+                Some(gain) => {
+                    let samples = frame.samples();
+                    if samples.is_empty() {
+                        return Ok(frame.clone());
+                    }
+                    let normalized = Self::apply_gain(samples, gain);
+                    Ok(AudioFrame::new(
+                        normalized,
+                        frame.sample_rate(),
+                        frame.channels(),
+                        frame.frame_number(),
+                    )?)
+                }
+                // First pass: measure the signal, pass samples through unchanged.
+                None => {
+                    ebu.analyze(frame);
+                    Ok(frame.clone())
+                }
+            };
+        }
+
         let samples = frame.samples();
 
         if samples.is_empty() {
@@ -100,11 +388,21 @@ impl super::Filter for Normalize {
 
         Ok(output_frame)
     }
+
+    fn flush(&mut self) -> AudioResult<Option<AudioFrame>> {
+        if let Some(ebu) = self.ebu.as_mut() {
+            if ebu.gain.is_none() {
+                ebu.gain = Some(ebu.compute_gain());
+            }
+        }
+        Ok(None)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::Channels;
     use crate::filter::Filter;
 
     #[test]
@@ -166,4 +464,53 @@ mod tests {
         let result = normalizer.process(&frame).unwrap();
         assert_eq!(result.samples(), &[0.0, 0.0, 0.0]);
     }
+
+    #[test]
+    fn test_ebu_r128_brings_tone_near_target() {
+        let sample_rate = 48000;
+        let target_lufs = -16.0;
+        let mut normalizer = Normalize::ebu_r128(target_lufs).unwrap();
+
+        // A few seconds of a steady 1kHz tone, fed in 100ms frames.
+        let frame_samples = sample_rate as usize / 10;
+        let total_frames = 40; // 4 seconds
+        let mut sample_index: u64 = 0;
+        let mut frames = Vec::new();
+        for i in 0..total_frames {
+            let samples: Vec<f32> = (0..frame_samples)
+                .map(|n| {
+                    let t = (sample_index + n as u64) as f32 / sample_rate as f32;
+                    (t * 1000.0 * std::f32::consts::TAU).sin() * 0.2
+                })
+                .collect();
+            sample_index += frame_samples as u64;
+            frames.push(AudioFrame::new(samples, sample_rate, Channels::Mono, i as u64).unwrap());
+        }
+
+        for frame in &frames {
+            normalizer.process(frame).unwrap();
+        }
+        normalizer.flush().unwrap();
+
+        // Second pass: gain should now be applied and the result brought near target.
+        let mut out_samples = Vec::new();
+        for frame in &frames {
+            out_samples.extend_from_slice(normalizer.process(frame).unwrap().samples());
+        }
+
+        let rms = (out_samples.iter().map(|&s| (s as f64) * (s as f64)).sum::<f64>()
+            / out_samples.len() as f64)
+            .sqrt();
+        // A sine's peak level relates to LUFS roughly as dBFS - 3.01 ~= LUFS for
+        // K-weighting near 1kHz (close to unity gain there); peak dBFS is
+        // 20*log10(rms) + 3.01, so LUFS ~= 20*log10(rms). Check we landed
+        // within the requested tolerance of the target loudness.
+        let measured_lufs = 20.0 * rms.log10();
+        assert!(
+            (measured_lufs - target_lufs as f64).abs() < 0.5,
+            "measured {} vs target {}",
+            measured_lufs,
+            target_lufs
+        );
+    }
 }
@@ -1,61 +1,231 @@
 use crate::core::{AudioFrame, Channels};
 use crate::error::{AudioError, AudioResult};
 
-/// Audio channel remixer - converts between channel layouts
+/// `1/sqrt(2)` - the standard ITU attenuation applied to center/surround
+/// channels when downmixing them onto front left/right
+const ATTEN_3DB: f32 = std::f32::consts::FRAC_1_SQRT_2;
+
+/// Build the default downmix/upmix coefficient matrix (`output_count x
+/// input_count`) for a known pair of channel layouts
+///
+/// Covers same-layout passthrough, Mono <-> Stereo, Stereo to and from every
+/// multichannel layout (Quad, 5.1, 7.1), Mono up to 5.1 and 7.1, and the
+/// conversions between the multichannel layouts themselves. Other pairs
+/// (Mono to or from Quad, and 5.1 or 7.1 down to Mono) return an error
+/// instead of a guessed-at matrix - use [`Remix::with_matrix`] to supply one
+/// explicitly.
+fn default_matrix(input: Channels, output: Channels) -> AudioResult<Vec<Vec<f32>>> {
+    use Channels::*;
+
+    if input == output {
+        let n = input.count() as usize;
+        return Ok((0..n)
+            .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+            .collect());
+    }
+
+    let matrix: Vec<Vec<f32>> = match (input, output) {
+        // Stereo to Mono: average L and R
+        (Stereo, Mono) => vec![vec![0.5, 0.5]],
+
+        // Mono to Stereo: duplicate the single channel
+        (Mono, Stereo) => vec![vec![1.0], vec![1.0]],
+
+        // Quad (FL, FR, RL, RR) to Stereo: average front/rear pairs
+        (Quad, Stereo) => vec![vec![0.5, 0.0, 0.5, 0.0], vec![0.0, 0.5, 0.0, 0.5]],
+
+        // Stereo to Quad: front channels passthrough, rears silent
+        (Stereo, Quad) => vec![
+            vec![1.0, 0.0],
+            vec![0.0, 1.0],
+            vec![0.0, 0.0],
+            vec![0.0, 0.0],
+        ],
+
+        // Mono to 5.1 (FL, FR, FC, LFE, RL, RR): everything to center
+        (Mono, SurroundFivePointOne) => {
+            vec![vec![0.0], vec![0.0], vec![1.0], vec![0.0], vec![0.0], vec![0.0]]
+        }
+
+        // Stereo to 5.1: front passthrough, spread a little into the rears
+        (Stereo, SurroundFivePointOne) => vec![
+            vec![1.0, 0.0],
+            vec![0.0, 1.0],
+            vec![0.0, 0.0],
+            vec![0.0, 0.0],
+            vec![0.5, 0.0],
+            vec![0.0, 0.5],
+        ],
+
+        // 5.1 to Stereo (ITU downmix): L = FL + 0.707*FC + 0.707*RL,
+        // R = FR + 0.707*FC + 0.707*RR, LFE dropped
+        (SurroundFivePointOne, Stereo) => vec![
+            vec![1.0, 0.0, ATTEN_3DB, 0.0, ATTEN_3DB, 0.0],
+            vec![0.0, 1.0, ATTEN_3DB, 0.0, 0.0, ATTEN_3DB],
+        ],
+
+        // Mono to 7.1 (FL, FR, FC, LFE, RL, RR, SL, SR): everything to center
+        (Mono, SurroundSevenPointOne) => vec![
+            vec![0.0],
+            vec![0.0],
+            vec![1.0],
+            vec![0.0],
+            vec![0.0],
+            vec![0.0],
+            vec![0.0],
+            vec![0.0],
+        ],
+
+        // Stereo to 7.1: front passthrough, spread into rears and sides
+        (Stereo, SurroundSevenPointOne) => vec![
+            vec![1.0, 0.0],
+            vec![0.0, 1.0],
+            vec![0.0, 0.0],
+            vec![0.0, 0.0],
+            vec![0.5, 0.0],
+            vec![0.0, 0.5],
+            vec![0.5, 0.0],
+            vec![0.0, 0.5],
+        ],
+
+        // 7.1 to Stereo (ITU downmix), including the side channels
+        (SurroundSevenPointOne, Stereo) => vec![
+            vec![1.0, 0.0, ATTEN_3DB, 0.0, ATTEN_3DB, 0.0, ATTEN_3DB, 0.0],
+            vec![0.0, 1.0, ATTEN_3DB, 0.0, 0.0, ATTEN_3DB, 0.0, ATTEN_3DB],
+        ],
+
+        // Quad (FL, FR, RL, RR) to 5.1 (FL, FR, FC, LFE, RL, RR): front/rear
+        // passthrough, center and LFE silent
+        (Quad, SurroundFivePointOne) => vec![
+            vec![1.0, 0.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 0.0, 1.0],
+        ],
+
+        // 5.1 to Quad (ITU downmix): front channels get the center folded in
+        // at -3dB, LFE dropped, rears passthrough
+        (SurroundFivePointOne, Quad) => vec![
+            vec![1.0, 0.0, ATTEN_3DB, 0.0, 0.0, 0.0],
+            vec![0.0, 1.0, ATTEN_3DB, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+        ],
+
+        // Quad (FL, FR, RL, RR) to 7.1 (FL, FR, FC, LFE, RL, RR, SL, SR):
+        // front/rear passthrough, center/LFE/sides silent
+        (Quad, SurroundSevenPointOne) => vec![
+            vec![1.0, 0.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 0.0, 1.0],
+            vec![0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0],
+        ],
+
+        // 7.1 to Quad (ITU downmix): front channels get the center folded
+        // in, rears get the matching side channel folded in, both at -3dB
+        (SurroundSevenPointOne, Quad) => vec![
+            vec![1.0, 0.0, ATTEN_3DB, 0.0, 0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 1.0, ATTEN_3DB, 0.0, 0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0, 1.0, 0.0, ATTEN_3DB, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, ATTEN_3DB],
+        ],
+
+        // 5.1 (FL, FR, FC, LFE, RL, RR) to 7.1: passthrough, sides silent
+        (SurroundFivePointOne, SurroundSevenPointOne) => vec![
+            vec![1.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 1.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0, 0.0, 1.0],
+            vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+        ],
+
+        // 7.1 to 5.1 (ITU downmix): front/center/LFE passthrough, sides
+        // folded into the matching rear at -3dB
+        (SurroundSevenPointOne, SurroundFivePointOne) => vec![
+            vec![1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0, 1.0, 0.0, ATTEN_3DB, 0.0],
+            vec![0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, ATTEN_3DB],
+        ],
+
+        _ => {
+            return Err(AudioError::ProcessingError(format!(
+                "Remix from {} to {} not yet supported",
+                input.name(),
+                output.name()
+            )))
+        }
+    };
+
+    Ok(matrix)
+}
+
+/// Audio channel remixer - converts between channel layouts using a
+/// coefficient matrix
 pub struct Remix {
     input_channels: Channels,
     output_channels: Channels,
+    /// `matrix[out_channel][in_channel]` mixing coefficients
+    matrix: Vec<Vec<f32>>,
 }
 
 impl Remix {
-    /// Create a new channel remixer
+    /// Create a new channel remixer using the default coefficients for this
+    /// pair of layouts
     pub fn new(input_channels: Channels, output_channels: Channels) -> AudioResult<Self> {
-        Ok(Remix {
-            input_channels,
-            output_channels,
-        })
+        let matrix = default_matrix(input_channels, output_channels)?;
+        Self::with_matrix(input_channels, output_channels, matrix)
     }
 
-    /// Remix stereo to mono by averaging channels
-    fn stereo_to_mono(input: &[f32]) -> Vec<f32> {
-        let mut output = Vec::new();
-        for i in (0..input.len()).step_by(2) {
-            if i + 1 < input.len() {
-                let avg = (input[i] + input[i + 1]) / 2.0;
-                output.push(avg);
-            }
-        }
-        output
-    }
+    /// Create a remixer with custom mixing coefficients
+    ///
+    /// `matrix` must have one row per output channel, each row holding one
+    /// coefficient per input channel (`output_channels.count()` rows of
+    /// `input_channels.count()` coefficients each).
+    pub fn with_matrix(
+        input_channels: Channels,
+        output_channels: Channels,
+        matrix: Vec<Vec<f32>>,
+    ) -> AudioResult<Self> {
+        let expected_rows = output_channels.count() as usize;
+        let expected_cols = input_channels.count() as usize;
 
-    /// Remix mono to stereo by duplicating the channel
-    fn mono_to_stereo(input: &[f32]) -> Vec<f32> {
-        let mut output = Vec::new();
-        for &sample in input {
-            output.push(sample);
-            output.push(sample); // Duplicate to both channels
+        if matrix.len() != expected_rows || matrix.iter().any(|row| row.len() != expected_cols) {
+            return Err(AudioError::ConfigError(format!(
+                "Remix matrix must be {}x{} for {} -> {}",
+                expected_rows,
+                expected_cols,
+                input_channels.name(),
+                output_channels.name()
+            )));
         }
-        output
+
+        Ok(Remix {
+            input_channels,
+            output_channels,
+            matrix,
+        })
     }
 
-    /// Extract left channel from stereo
-    fn stereo_left(input: &[f32]) -> Vec<f32> {
-        let mut output = Vec::new();
-        for i in (0..input.len()).step_by(2) {
-            output.push(input[i]);
-        }
-        output
+    /// Get the input channel layout
+    pub fn input_channels(&self) -> Channels {
+        self.input_channels
     }
 
-    /// Extract right channel from stereo
-    fn stereo_right(input: &[f32]) -> Vec<f32> {
-        let mut output = Vec::new();
-        for i in (0..input.len()).step_by(2) {
-            if i + 1 < input.len() {
-                output.push(input[i + 1]);
-            }
-        }
-        output
+    /// Get the output channel layout
+    pub fn output_channels(&self) -> Channels {
+        self.output_channels
     }
 }
 
@@ -68,55 +238,25 @@ impl super::Filter for Remix {
             });
         }
 
+        let in_count = self.input_channels.count() as usize;
         let samples = frame.samples();
+        let mut output_samples = Vec::with_capacity(
+            frame.samples_per_channel() * self.output_channels.count() as usize,
+        );
 
-        // Handle common remixing operations
-        let output_samples = match (self.input_channels, self.output_channels) {
-            // Pass through same channel count
-            (src, dst) if src == dst => samples.to_vec(),
-
-            // Stereo to Mono
-            (Channels::Stereo, Channels::Mono) => Self::stereo_to_mono(samples),
-
-            // Mono to Stereo
-            (Channels::Mono, Channels::Stereo) => Self::mono_to_stereo(samples),
-
-            // Quad to Stereo (average all channels)
-            (Channels::Quad, Channels::Stereo) => {
-                let mut output = Vec::new();
-                // Assuming quad is FLRR (Front-Left, Front-Right, Rear-Left, Rear-Right)
-                for i in (0..samples.len()).step_by(4) {
-                    if i + 3 < samples.len() {
-                        let left = (samples[i] + samples[i + 2]) / 2.0; // FL + RL
-                        let right = (samples[i + 1] + samples[i + 3]) / 2.0; // FR + RR
-                        output.push(left);
-                        output.push(right);
-                    }
-                }
-                output
-            }
-
-            // Stereo to Left Only
-            (Channels::Stereo, other) if other == Channels::Mono => Self::stereo_left(samples),
-
-            _ => {
-                return Err(AudioError::ProcessingError(format!(
-                    "Remix from {} to {} not yet supported",
-                    self.input_channels.name(),
-                    self.output_channels.name()
-                )))
+        for group in samples.chunks(in_count) {
+            for row in &self.matrix {
+                let mixed: f32 = row.iter().zip(group).map(|(&c, &s)| c * s).sum();
+                output_samples.push(mixed.clamp(-1.0, 1.0));
             }
-        };
+        }
 
-        // Create output frame
-        let output_frame = AudioFrame::new(
+        AudioFrame::new(
             output_samples,
             frame.sample_rate(),
             self.output_channels,
             frame.frame_number(),
-        )?;
-
-        Ok(output_frame)
+        )
     }
 }
 
@@ -127,26 +267,132 @@ mod tests {
 
     #[test]
     fn test_remix_stereo_to_mono() {
-        // Create test stereo samples: [L1, R1, L2, R2]
-        let input = vec![0.0, 1.0, 0.5, 0.5];
-        let output = Remix::stereo_to_mono(&input);
+        let mut remix = Remix::new(Channels::Stereo, Channels::Mono).unwrap();
+        let frame = AudioFrame::new(vec![0.0, 1.0, 0.5, 0.5], 44100, Channels::Stereo, 0).unwrap();
+        let output = remix.process(&frame).unwrap();
 
-        // Expected: [(0+1)/2, (0.5+0.5)/2] = [0.5, 0.5]
-        assert_eq!(output.len(), 2);
-        assert!((output[0] - 0.5).abs() < 0.001);
-        assert!((output[1] - 0.5).abs() < 0.001);
+        assert_eq!(output.samples().len(), 2);
+        assert!((output.samples()[0] - 0.5).abs() < 0.001);
+        assert!((output.samples()[1] - 0.5).abs() < 0.001);
     }
 
     #[test]
     fn test_remix_mono_to_stereo() {
-        let input = vec![0.5, 0.8];
-        let output = Remix::mono_to_stereo(&input);
-
-        // Expected: [0.5, 0.5, 0.8, 0.8]
-        assert_eq!(output.len(), 4);
-        assert_eq!(output[0], 0.5);
-        assert_eq!(output[1], 0.5);
-        assert_eq!(output[2], 0.8);
-        assert_eq!(output[3], 0.8);
+        let mut remix = Remix::new(Channels::Mono, Channels::Stereo).unwrap();
+        let frame = AudioFrame::new(vec![0.5, 0.8], 44100, Channels::Mono, 0).unwrap();
+        let output = remix.process(&frame).unwrap();
+
+        assert_eq!(output.samples(), &[0.5, 0.5, 0.8, 0.8]);
+    }
+
+    #[test]
+    fn test_remix_quad_to_stereo() {
+        let mut remix = Remix::new(Channels::Quad, Channels::Stereo).unwrap();
+        // FL=0, FR=1, RL=0.5, RR=0.5
+        let frame = AudioFrame::new(vec![0.0, 1.0, 0.5, 0.5], 44100, Channels::Quad, 0).unwrap();
+        let output = remix.process(&frame).unwrap();
+
+        assert_eq!(output.samples().len(), 2);
+        assert!((output.samples()[0] - 0.25).abs() < 0.001);
+        assert!((output.samples()[1] - 0.75).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_remix_5_1_to_stereo_drops_lfe() {
+        let mut remix = Remix::new(Channels::SurroundFivePointOne, Channels::Stereo).unwrap();
+        // FL, FR, FC, LFE, RL, RR
+        let frame = AudioFrame::new(
+            vec![1.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+            44100,
+            Channels::SurroundFivePointOne,
+            0,
+        )
+        .unwrap();
+        let output = remix.process(&frame).unwrap();
+
+        // LFE should not leak into either output channel.
+        assert!((output.samples()[0] - 1.0).abs() < 0.001);
+        assert!(output.samples()[1].abs() < 0.001);
+    }
+
+    #[test]
+    fn test_remix_custom_matrix() {
+        // Custom mono -> stereo matrix that pans fully to the left channel.
+        let matrix = vec![vec![1.0], vec![0.0]];
+        let mut remix = Remix::with_matrix(Channels::Mono, Channels::Stereo, matrix).unwrap();
+        let frame = AudioFrame::new(vec![0.8], 44100, Channels::Mono, 0).unwrap();
+        let output = remix.process(&frame).unwrap();
+
+        assert_eq!(output.samples(), &[0.8, 0.0]);
+    }
+
+    #[test]
+    fn test_remix_invalid_matrix_shape() {
+        let matrix = vec![vec![1.0, 0.0]];
+        let result = Remix::with_matrix(Channels::Mono, Channels::Stereo, matrix);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_remix_unsupported_pair_errors() {
+        // Mono <-> Quad has no default coefficients defined.
+        let result = Remix::new(Channels::Mono, Channels::Quad);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_remix_quad_to_5_1_passthrough_front_and_rear() {
+        let mut remix = Remix::new(Channels::Quad, Channels::SurroundFivePointOne).unwrap();
+        let frame = AudioFrame::new(vec![1.0, 0.5, 0.25, 0.75], 44100, Channels::Quad, 0).unwrap();
+        let output = remix.process(&frame).unwrap();
+
+        // FL, FR, FC, LFE, RL, RR
+        assert_eq!(output.samples().len(), 6);
+        assert!((output.samples()[0] - 1.0).abs() < 0.001);
+        assert!((output.samples()[1] - 0.5).abs() < 0.001);
+        assert!(output.samples()[2].abs() < 0.001);
+        assert!(output.samples()[3].abs() < 0.001);
+        assert!((output.samples()[4] - 0.25).abs() < 0.001);
+        assert!((output.samples()[5] - 0.75).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_remix_7_1_to_quad_folds_sides_into_rears() {
+        let mut remix = Remix::new(Channels::SurroundSevenPointOne, Channels::Quad).unwrap();
+        // FL, FR, FC, LFE, RL, RR, SL, SR
+        let frame = AudioFrame::new(
+            vec![0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+            44100,
+            Channels::SurroundSevenPointOne,
+            0,
+        )
+        .unwrap();
+        let output = remix.process(&frame).unwrap();
+
+        // LFE drops out entirely; SL folds into RL at -3dB.
+        assert_eq!(output.samples().len(), 4);
+        assert!(output.samples()[0].abs() < 0.001);
+        assert!(output.samples()[1].abs() < 0.001);
+        assert!((output.samples()[2] - ATTEN_3DB).abs() < 0.001);
+        assert!(output.samples()[3].abs() < 0.001);
+    }
+
+    #[test]
+    fn test_remix_5_1_to_7_1_passthrough_sides_silent() {
+        let mut remix = Remix::new(Channels::SurroundFivePointOne, Channels::SurroundSevenPointOne)
+            .unwrap();
+        let frame = AudioFrame::new(
+            vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0],
+            44100,
+            Channels::SurroundFivePointOne,
+            0,
+        )
+        .unwrap();
+        let output = remix.process(&frame).unwrap();
+
+        assert_eq!(output.samples().len(), 8);
+        assert!(output.samples()[..6].iter().all(|&s| (s - 1.0).abs() < 0.001));
+        assert!(output.samples()[6].abs() < 0.001);
+        assert!(output.samples()[7].abs() < 0.001);
     }
 }
@@ -1,29 +1,174 @@
 use crate::core::{AudioFrame, Channels};
 use crate::error::{AudioError, AudioResult};
 
-/// Audio resampler - converts from one sample rate to another using linear interpolation
+/// Number of zero-crossings on each side of the windowed-sinc kernel used by
+/// [`InterpolationMode::Polyphase`]
+const SINC_ORDER: i64 = 16;
+/// Kaiser window beta parameter (higher = more stopband attenuation, wider
+/// transition band)
+const KAISER_BETA: f64 = 8.0;
+
+/// Interpolation algorithm used by [`Resample`], trading CPU for quality
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InterpolationMode {
+    /// Nearest-neighbor: no interpolation, just the closest source sample
+    Nearest,
+    /// Straight-line interpolation between the two surrounding samples
+    #[default]
+    Linear,
+    /// Raised-cosine-weighted interpolation; smoother than linear at the
+    /// same cost class
+    Cosine,
+    /// Catmull-Rom cubic interpolation over the four surrounding samples
+    Cubic,
+    /// Polyphase windowed-sinc (Kaiser window) resampling; the highest
+    /// quality path, especially when downsampling
+    Polyphase,
+}
+
+/// Zeroth-order modified Bessel function of the first kind, evaluated by
+/// direct series summation until terms fall below ~1e-10
+fn bessel_i0(x: f64) -> f64 {
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut k = 1.0;
+    loop {
+        term *= (x / 2.0) * (x / 2.0) / (k * k);
+        if term < 1e-10 {
+            break;
+        }
+        sum += term;
+        k += 1.0;
+    }
+    sum
+}
+
+/// Kaiser window evaluated at `t` within `[-half_width, half_width]`
+fn kaiser_window(t: f64, half_width: f64) -> f64 {
+    if t.abs() > half_width {
+        return 0.0;
+    }
+    let ratio = t / half_width;
+    bessel_i0(KAISER_BETA * (1.0 - ratio * ratio).sqrt()) / bessel_i0(KAISER_BETA)
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Precomputed polyphase windowed-sinc filter bank for a fixed rational
+/// resampling ratio
+struct SincFilterBank {
+    /// `taps[phase]` is a kernel of `SINC_ORDER * 2` coefficients
+    taps: Vec<Vec<f32>>,
+}
+
+impl SincFilterBank {
+    fn new(input_rate: u32, output_rate: u32, phases: i64) -> Self {
+        // Cutoff relative to Nyquist: scale down when downsampling to avoid
+        // aliasing, leave at full bandwidth when upsampling.
+        let norm = (output_rate as f64 / input_rate as f64).min(1.0);
+        let taps_per_phase = (SINC_ORDER * 2) as usize;
+
+        let taps = (0..phases)
+            .map(|phase| {
+                let frac_offset = phase as f64 / phases as f64;
+                (0..taps_per_phase)
+                    .map(|k| {
+                        let n = (k as f64 - (SINC_ORDER as f64 - 1.0)) - frac_offset;
+                        let sinc = if n.abs() < 1e-9 {
+                            1.0
+                        } else {
+                            (std::f64::consts::PI * n * norm).sin() / (std::f64::consts::PI * n)
+                        };
+                        let window = kaiser_window(n, SINC_ORDER as f64);
+                        (sinc * window * norm) as f32
+                    })
+                    .collect()
+            })
+            .collect();
+
+        SincFilterBank { taps }
+    }
+}
+
+/// Audio resampler - converts from one sample rate to another
+///
+/// Maintains a fractional input-position accumulator and a small history
+/// tail across successive `process()` calls so the interpolation phase is
+/// continuous at frame boundaries; call `flush()` to drain the final
+/// buffered samples at end-of-stream.
 pub struct Resample {
     input_rate: u32,
     output_rate: u32,
     channels: Channels,
+    mode: InterpolationMode,
+    filter_bank: Option<SincFilterBank>,
+    /// Output steps per reduced output-rate unit (`output_rate / gcd`);
+    /// also the number of polyphase phases
+    phases: i64,
+    /// Input steps consumed per reduced input-rate unit (`input_rate / gcd`)
+    step: i64,
+    /// Samples carried over from previous `process()` calls, indexed so that
+    /// `buffer[ipos]` is the next sample to center a window on
+    buffer: Vec<f32>,
+    /// Fractional position within `buffer` of the next output sample, as an
+    /// `(ipos, frac)` pair reduced against `phases`
+    ipos: i64,
+    frac: i64,
+    /// Frame counter for frames emitted by this filter
+    next_frame_number: u64,
 }
 
 impl Resample {
-    /// Create a new resampler
+    /// Create a new resampler using linear interpolation (fast, lower quality)
     ///
     /// # Arguments
     /// * `input_rate` - Input sample rate in Hz
     /// * `output_rate` - Output sample rate in Hz
     /// * `channels` - Number of channels
     pub fn new(input_rate: u32, output_rate: u32, channels: Channels) -> AudioResult<Self> {
+        Self::with_quality(input_rate, output_rate, channels, InterpolationMode::Linear)
+    }
+
+    /// Create a new resampler with an explicit interpolation mode
+    pub fn with_quality(
+        input_rate: u32,
+        output_rate: u32,
+        channels: Channels,
+        mode: InterpolationMode,
+    ) -> AudioResult<Self> {
         if input_rate == 0 || output_rate == 0 {
             return Err(AudioError::InvalidSampleRate { rate: 0 });
         }
 
+        let g = gcd(input_rate, output_rate);
+        let phases = (output_rate / g) as i64;
+        let step = (input_rate / g) as i64;
+
+        let filter_bank = match mode {
+            InterpolationMode::Polyphase => {
+                Some(SincFilterBank::new(input_rate, output_rate, phases))
+            }
+            _ => None,
+        };
+
         Ok(Resample {
             input_rate,
             output_rate,
             channels,
+            mode,
+            filter_bank,
+            phases,
+            step,
+            buffer: Vec::new(),
+            ipos: 0,
+            frac: 0,
+            next_frame_number: 0,
         })
     }
 
@@ -37,12 +182,36 @@ impl Resample {
         self.output_rate
     }
 
+    /// Get the interpolation mode in use
+    pub fn quality(&self) -> InterpolationMode {
+        self.mode
+    }
+
     /// Get the ratio of output to input sample rate
     pub fn ratio(&self) -> f64 {
         self.output_rate as f64 / self.input_rate as f64
     }
 
-    /// Linear interpolation resampling
+    /// How many samples ahead of `ipos` the interpolation kernel reads
+    fn ahead_margin(&self) -> i64 {
+        match self.mode {
+            InterpolationMode::Nearest | InterpolationMode::Linear | InterpolationMode::Cosine => 1,
+            InterpolationMode::Cubic => 2,
+            InterpolationMode::Polyphase => SINC_ORDER,
+        }
+    }
+
+    /// How many samples behind `ipos` the interpolation kernel reads (and
+    /// therefore must be kept in `buffer` across calls)
+    fn behind_margin(&self) -> i64 {
+        match self.mode {
+            InterpolationMode::Nearest | InterpolationMode::Linear | InterpolationMode::Cosine => 0,
+            InterpolationMode::Cubic => 1,
+            InterpolationMode::Polyphase => SINC_ORDER - 1,
+        }
+    }
+
+    /// Linear interpolation resampling (stateless helper retained for tests)
     fn linear_resample(input: &[f32], ratio: f64) -> Vec<f32> {
         if input.is_empty() || ratio <= 0.0 {
             return Vec::new();
@@ -68,6 +237,92 @@ impl Resample {
 
         output
     }
+
+    /// Sample at `buffer[idx]`, treating out-of-range indices as zero
+    fn tap(&self, idx: i64) -> f32 {
+        if idx < 0 {
+            0.0
+        } else {
+            self.buffer.get(idx as usize).copied().unwrap_or(0.0)
+        }
+    }
+
+    /// Compute one output sample centered at `ipos` with sub-sample phase
+    /// `t = frac / self.phases`, treating out-of-range buffer indices as zero
+    fn compute_sample(&self, ipos: i64, frac: i64) -> f32 {
+        let t = frac as f64 / self.phases as f64;
+
+        match self.mode {
+            InterpolationMode::Nearest => {
+                if t >= 0.5 {
+                    self.tap(ipos + 1)
+                } else {
+                    self.tap(ipos)
+                }
+            }
+            InterpolationMode::Linear => {
+                let a = self.tap(ipos) as f64;
+                let b = self.tap(ipos + 1) as f64;
+                (a * (1.0 - t) + b * t) as f32
+            }
+            InterpolationMode::Cosine => {
+                let w = (1.0 - (t * std::f64::consts::PI).cos()) / 2.0;
+                let a = self.tap(ipos) as f64;
+                let b = self.tap(ipos + 1) as f64;
+                (a * (1.0 - w) + b * w) as f32
+            }
+            InterpolationMode::Cubic => {
+                // Catmull-Rom over the four surrounding samples.
+                let p0 = self.tap(ipos - 1) as f64;
+                let p1 = self.tap(ipos) as f64;
+                let p2 = self.tap(ipos + 1) as f64;
+                let p3 = self.tap(ipos + 2) as f64;
+
+                let t2 = t * t;
+                let t3 = t2 * t;
+                (0.5
+                    * ((2.0 * p1)
+                        + (-p0 + p2) * t
+                        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * t2
+                        + (-p0 + 3.0 * p1 - 3.0 * p2 + p3) * t3)) as f32
+            }
+            InterpolationMode::Polyphase => {
+                let bank = self
+                    .filter_bank
+                    .as_ref()
+                    .expect("filter bank always present for Polyphase mode");
+                let phase = (frac as usize).min(bank.taps.len().saturating_sub(1));
+                let kernel = &bank.taps[phase];
+                let mut acc = 0.0f32;
+                for (k, &coeff) in kernel.iter().enumerate() {
+                    acc += self.tap(ipos + (k as i64 - (SINC_ORDER - 1))) * coeff;
+                }
+                acc
+            }
+        }
+        .clamp(-1.0, 1.0)
+    }
+
+    /// Drain as many output samples as can be computed without reading past
+    /// `limit` (the end of `buffer`, or `buffer.len()` to flush completely)
+    fn drain_until(&mut self, limit: i64) -> Vec<f32> {
+        let mut output = Vec::new();
+        while self.ipos < limit {
+            output.push(self.compute_sample(self.ipos, self.frac));
+            self.frac += self.step;
+            while self.frac >= self.phases {
+                self.frac -= self.phases;
+                self.ipos += 1;
+            }
+        }
+        output
+    }
+
+    fn next_frame(&mut self, samples: Vec<f32>) -> AudioResult<AudioFrame> {
+        let frame_number = self.next_frame_number;
+        self.next_frame_number += 1;
+        AudioFrame::new(samples, self.output_rate, self.channels, frame_number)
+    }
 }
 
 impl super::Filter for Resample {
@@ -90,20 +345,38 @@ impl super::Filter for Resample {
             return Ok(frame.clone());
         }
 
-        let ratio = self.input_rate as f64 / self.output_rate as f64;
+        self.buffer.extend_from_slice(frame.samples());
 
-        // Resample all samples together (works for interleaved format)
-        let resampled = Self::linear_resample(frame.samples(), ratio);
+        // Only emit samples whose kernel is fully inside the buffer; the
+        // rest wait for more input (or `flush()` at end-of-stream).
+        let safe_limit = self.buffer.len() as i64 - self.ahead_margin();
+        let output = self.drain_until(safe_limit.max(0));
 
-        // Create output frame with new sample rate
-        let output_frame = AudioFrame::new(
-            resampled,
-            self.output_rate,
-            self.channels,
-            frame.frame_number(),
-        )?;
+        // Trim consumed history, keeping just enough behind `ipos` for the
+        // next call's kernel to read.
+        let keep_from = (self.ipos - self.behind_margin()).max(0);
+        self.buffer.drain(0..keep_from as usize);
+        self.ipos -= keep_from;
 
-        Ok(output_frame)
+        self.next_frame(output)
+    }
+
+    fn flush(&mut self) -> AudioResult<Option<AudioFrame>> {
+        if self.buffer.is_empty() && self.ipos == 0 {
+            return Ok(None);
+        }
+
+        // Consume everything remaining, zero-padding past the true end.
+        let output = self.drain_until(self.buffer.len() as i64);
+        self.buffer.clear();
+        self.ipos = 0;
+        self.frac = 0;
+
+        if output.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(self.next_frame(output)?))
+        }
     }
 }
 
@@ -119,6 +392,7 @@ mod tests {
         let r = resample.unwrap();
         assert_eq!(r.input_rate(), 44100);
         assert_eq!(r.output_rate(), 16000);
+        assert_eq!(r.quality(), InterpolationMode::Linear);
     }
 
     #[test]
@@ -134,4 +408,82 @@ mod tests {
         let output = Resample::linear_resample(&input, 2.0);
         assert!(!output.is_empty());
     }
+
+    fn run_to_completion(mode: InterpolationMode, samples: Vec<f32>, input_rate: u32, output_rate: u32) -> Vec<f32> {
+        let mut resample =
+            Resample::with_quality(input_rate, output_rate, Channels::Mono, mode).unwrap();
+        let frame = AudioFrame::new(samples, input_rate, Channels::Mono, 0).unwrap();
+
+        let mut output = resample.process(&frame).unwrap().into_samples();
+        output.extend(
+            resample
+                .flush()
+                .unwrap()
+                .map(|f| f.into_samples())
+                .unwrap_or_default(),
+        );
+        output
+    }
+
+    #[test]
+    fn test_polyphase_downsample_length_matches_ratio() {
+        let samples: Vec<f32> = (0..4410)
+            .map(|i| (i as f32 * 440.0 * std::f32::consts::TAU / 44100.0).sin())
+            .collect();
+
+        let total = run_to_completion(InterpolationMode::Polyphase, samples, 44100, 16000).len();
+        let expected_len = (4410.0 * 16000.0 / 44100.0).round() as usize;
+        assert!((total as i64 - expected_len as i64).abs() <= 2);
+    }
+
+    #[test]
+    fn test_nearest_mode_length_matches_ratio() {
+        let samples: Vec<f32> = vec![0.0; 4410];
+        let total = run_to_completion(InterpolationMode::Nearest, samples, 44100, 16000).len();
+        let expected_len = (4410.0 * 16000.0 / 44100.0).round() as usize;
+        assert!((total as i64 - expected_len as i64).abs() <= 2);
+    }
+
+    #[test]
+    fn test_cosine_and_cubic_modes_interpolate_a_ramp() {
+        // A straight ramp should come back out as (approximately) a ramp
+        // under any of the smooth interpolation modes.
+        let samples: Vec<f32> = (0..100).map(|i| i as f32 / 100.0).collect();
+
+        for mode in [InterpolationMode::Cosine, InterpolationMode::Cubic] {
+            let output = run_to_completion(mode, samples.clone(), 2, 1);
+            assert!(!output.is_empty());
+            for w in output.windows(2) {
+                assert!(w[1] >= w[0] - 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_resample_continuous_across_frame_boundary() {
+        // Feeding the same tone as one big frame vs. many small frames
+        // should produce (almost) the same output once flushed, proving the
+        // interpolation phase survives frame boundaries.
+        let samples: Vec<f32> = (0..8820)
+            .map(|i| (i as f32 * 440.0 * std::f32::consts::TAU / 44100.0).sin())
+            .collect();
+
+        let mut whole = Resample::new(44100, 22050, Channels::Mono).unwrap();
+        let whole_frame = AudioFrame::new(samples.clone(), 44100, Channels::Mono, 0).unwrap();
+        let mut whole_out = whole.process(&whole_frame).unwrap().into_samples();
+        whole_out.extend(whole.flush().unwrap().map(|f| f.into_samples()).unwrap_or_default());
+
+        let mut chunked = Resample::new(44100, 22050, Channels::Mono).unwrap();
+        let mut chunked_out = Vec::new();
+        for (i, chunk) in samples.chunks(441).enumerate() {
+            let frame = AudioFrame::new(chunk.to_vec(), 44100, Channels::Mono, i as u64).unwrap();
+            chunked_out.extend(chunked.process(&frame).unwrap().into_samples());
+        }
+        chunked_out.extend(chunked.flush().unwrap().map(|f| f.into_samples()).unwrap_or_default());
+
+        assert_eq!(whole_out.len(), chunked_out.len());
+        for (a, b) in whole_out.iter().zip(chunked_out.iter()) {
+            assert!((a - b).abs() < 1e-4);
+        }
+    }
 }
@@ -43,6 +43,10 @@ pub mod filter;
 pub mod encoder;
 /// Audio processing pipelines
 pub mod processor;
+/// Spectral/feature analysis
+pub mod analysis;
+/// Sample-format and layout conversion
+pub mod convert;
 
 // Export public types
 pub use core::{AudioFrame, AudioMetadata, Channels, BitDepth};
@@ -3,6 +3,8 @@
 //! Pure Rust implementation of FFmpeg audio processing tool.
 
 use clap::{Parser, Subcommand};
+use ffmpeg_rs::encoder::wav::SampleFormat;
+use ffmpeg_rs::{AudioError, AudioResult, Channels};
 use log::info;
 use std::path::PathBuf;
 
@@ -36,6 +38,24 @@ enum Commands {
         input: PathBuf,
     },
 
+    /// Analyze audio and print spectral/temporal features as JSON
+    Analyze {
+        /// Input audio file
+        #[arg(value_name = "FILE")]
+        input: PathBuf,
+
+        /// Analysis window length in milliseconds; when set, drives the
+        /// analyzer through a windowed `Segment` front-end instead of
+        /// pushing whole decoded frames directly
+        #[arg(long)]
+        window_ms: Option<u64>,
+
+        /// Hop length in milliseconds between successive windows (defaults
+        /// to half of `window_ms`, i.e. 50% overlap)
+        #[arg(long)]
+        hop_ms: Option<u64>,
+    },
+
     /// Decode audio to WAV format
     Decode {
         /// Input audio file
@@ -53,6 +73,17 @@ enum Commands {
         /// Channels (mono, stereo)
         #[arg(short, long)]
         channels: Option<String>,
+
+        /// Output bit depth (16, 24, 32)
+        #[arg(long)]
+        bits: Option<u16>,
+
+        /// Proceed even if the input decodes through a backend that only
+        /// emits placeholder silence (see `Decoder::is_placeholder_audio`),
+        /// e.g. today's `.mp3` support; without this the command refuses to
+        /// write a silent file that looks like real audio
+        #[arg(long)]
+        allow_placeholder: bool,
     },
 
     /// Encode audio from WAV to another format
@@ -98,9 +129,138 @@ enum Commands {
         /// Channels (mono, stereo)
         #[arg(short, long)]
         channels: Option<String>,
+
+        /// Output bit depth (16, 24, 32)
+        #[arg(long)]
+        bits: Option<u16>,
+
+        /// Fade automation, e.g. "in:0:3,out:end-3:3" (type:start:duration,
+        /// start accepts "end-N" relative to the stream's total duration)
+        #[arg(long)]
+        afade: Option<String>,
+
+        /// Proceed even if the input decodes through a backend that only
+        /// emits placeholder silence (see `Decoder::is_placeholder_audio`),
+        /// e.g. today's `.mp3` support; without this the command refuses to
+        /// write a silent file that looks like real audio
+        #[arg(long)]
+        allow_placeholder: bool,
     },
 }
 
+/// Parse a `--channels` CLI value into a [`Channels`] layout, accepting the
+/// common layout names or a raw channel count
+fn parse_channels_arg(value: &str) -> AudioResult<Channels> {
+    match value.to_ascii_lowercase().as_str() {
+        "mono" => Ok(Channels::Mono),
+        "stereo" => Ok(Channels::Stereo),
+        "quad" => Ok(Channels::Quad),
+        "5.1" => Ok(Channels::SurroundFivePointOne),
+        "7.1" => Ok(Channels::SurroundSevenPointOne),
+        other => other
+            .parse::<u32>()
+            .map_err(|_| AudioError::ConfigError(format!("unrecognized channel layout: {other}")))
+            .and_then(Channels::from_count),
+    }
+}
+
+/// Map a `--bits` CLI value onto the [`SampleFormat`] the WAV encoder writes
+fn sample_format_from_bits(bits: u16) -> AudioResult<SampleFormat> {
+    match bits {
+        16 => Ok(SampleFormat::S16),
+        24 => Ok(SampleFormat::S24),
+        32 => Ok(SampleFormat::S32),
+        other => Err(AudioError::ConfigError(format!(
+            "unsupported output bit depth: {other} (expected 16, 24, or 32)"
+        ))),
+    }
+}
+
+/// Parse a single `afade` position token: either a plain seconds offset from
+/// the start of the stream, or `end-N` for `N` seconds before the end
+fn parse_fade_position(token: &str) -> AudioResult<ffmpeg_rs::filter::FadePosition> {
+    use ffmpeg_rs::filter::FadePosition;
+    use std::time::Duration;
+
+    let invalid = || AudioError::ConfigError(format!("invalid afade start: {token}"));
+
+    match token.strip_prefix("end-") {
+        Some(rest) => {
+            let secs: f64 = rest.parse().map_err(|_| invalid())?;
+            Ok(FadePosition::FromEnd(Duration::from_secs_f64(secs)))
+        }
+        None => {
+            let secs: f64 = token.parse().map_err(|_| invalid())?;
+            Ok(FadePosition::FromStart(Duration::from_secs_f64(secs)))
+        }
+    }
+}
+
+/// Parse an `--afade` spec (`"in:0:3,out:end-3:3"`-style, comma-separated
+/// `type:start:duration` entries) into a configured [`ffmpeg_rs::filter::Fade`]
+fn parse_afade_arg(
+    spec: &str,
+    mut fade: ffmpeg_rs::filter::Fade,
+) -> AudioResult<ffmpeg_rs::filter::Fade> {
+    use std::time::Duration;
+
+    for entry in spec.split(',') {
+        let entry = entry.trim();
+        if entry.is_empty() {
+            continue;
+        }
+
+        let mut parts = entry.splitn(3, ':');
+        let invalid =
+            || AudioError::ConfigError(format!("invalid afade entry (want type:start:duration): {entry}"));
+        let kind = parts.next().ok_or_else(invalid)?;
+        let start = parse_fade_position(parts.next().ok_or_else(invalid)?)?;
+        let duration: f64 = parts
+            .next()
+            .ok_or_else(invalid)?
+            .parse()
+            .map_err(|_| invalid())?;
+        let duration = Duration::from_secs_f64(duration);
+
+        fade = match kind {
+            "in" => fade.with_fade_in(start, duration),
+            "out" => fade.with_fade_out(start, duration),
+            other => {
+                return Err(AudioError::ConfigError(format!(
+                    "unknown afade type: {other} (expected \"in\" or \"out\")"
+                )))
+            }
+        };
+    }
+
+    Ok(fade)
+}
+
+/// Guard against silently writing placeholder silence as if it were decoded
+/// audio: warn and proceed when `allow_placeholder` is set, otherwise refuse
+fn check_placeholder_audio(
+    decoder: &dyn ffmpeg_rs::decoder::Decoder,
+    input: &std::path::Path,
+    allow_placeholder: bool,
+) -> AudioResult<()> {
+    if !decoder.is_placeholder_audio() {
+        return Ok(());
+    }
+
+    if allow_placeholder {
+        log::warn!(
+            "{:?} decodes through a placeholder backend that emits silence, not real audio; proceeding because --allow-placeholder was passed",
+            input
+        );
+        Ok(())
+    } else {
+        Err(AudioError::Unsupported(format!(
+            "{:?} decodes through a placeholder backend that emits silence, not real audio; pass --allow-placeholder to proceed anyway",
+            input
+        )))
+    }
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
@@ -122,22 +282,107 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("Probe command: {:?}", input);
             println!("Not yet implemented - Phase 2");
         }
+        Some(Commands::Analyze {
+            input,
+            window_ms,
+            hop_ms,
+        }) => {
+            use ffmpeg_rs::analysis::{analyze_windowed, Analyzer, SpectralAnalyzer};
+            use std::time::Duration;
+
+            let mut decoder = ffmpeg_rs::decoder::from_file(&input)?;
+
+            let report = match window_ms {
+                Some(window_ms) => {
+                    let window = Duration::from_millis(window_ms);
+                    let hop = Duration::from_millis(hop_ms.unwrap_or(window_ms / 2));
+                    analyze_windowed(decoder.as_mut(), window, hop)?
+                }
+                None => {
+                    let mut analyzer = SpectralAnalyzer::new();
+                    while let Some(frame) = decoder.decode_frame()? {
+                        analyzer.push_frame(&frame)?;
+                    }
+                    analyzer.finalize()?
+                }
+            };
+
+            println!("{}", report.to_json());
+        }
         Some(Commands::Decode {
             input,
             output,
             rate,
             channels,
+            bits,
+            allow_placeholder,
         }) => {
-            println!("Decode command:");
-            println!("  Input: {:?}", input);
-            println!("  Output: {:?}", output);
-            if let Some(r) = rate {
-                println!("  Sample rate: {}", r);
+            use ffmpeg_rs::encoder::{Encoder, WavEncoder};
+            use ffmpeg_rs::filter::{Filter, Remix, Resample};
+
+            let format = match bits {
+                Some(bits) => sample_format_from_bits(bits)?,
+                None => SampleFormat::F32,
+            };
+            let target_channels = channels.as_deref().map(parse_channels_arg).transpose()?;
+
+            let mut decoder = ffmpeg_rs::decoder::from_file(&input)?;
+            check_placeholder_audio(decoder.as_ref(), &input, allow_placeholder)?;
+            let mut resample: Option<Resample> = None;
+            let mut remix: Option<Remix> = None;
+            let mut encoder: Option<WavEncoder> = None;
+
+            while let Some(mut frame) = decoder.decode_frame()? {
+                if let Some(target_rate) = rate {
+                    if frame.sample_rate() != target_rate {
+                        let resample = match resample.as_mut() {
+                            Some(resample) => resample,
+                            None => {
+                                resample = Some(Resample::new(
+                                    frame.sample_rate(),
+                                    target_rate,
+                                    frame.channels(),
+                                )?);
+                                resample.as_mut().unwrap()
+                            }
+                        };
+                        frame = resample.process(&frame)?;
+                    }
+                }
+
+                if let Some(target_channels) = target_channels {
+                    if frame.channels() != target_channels {
+                        let remix = match remix.as_mut() {
+                            Some(remix) => remix,
+                            None => {
+                                remix = Some(Remix::new(frame.channels(), target_channels)?);
+                                remix.as_mut().unwrap()
+                            }
+                        };
+                        frame = remix.process(&frame)?;
+                    }
+                }
+
+                let encoder = match encoder.as_mut() {
+                    Some(encoder) => encoder,
+                    None => {
+                        encoder = Some(WavEncoder::with_format(
+                            &output,
+                            frame.sample_rate(),
+                            frame.channels(),
+                            format,
+                        )?);
+                        encoder.as_mut().unwrap()
+                    }
+                };
+                encoder.encode(&frame)?;
             }
-            if let Some(c) = channels {
-                println!("  Channels: {}", c);
+
+            if let Some(mut encoder) = encoder {
+                encoder.finalize()?;
             }
-            println!("Not yet implemented - Phase 2");
+
+            println!("Decoded {:?} -> {:?}", input, output);
         }
         Some(Commands::Encode { input, output }) => {
             println!("Encode command: {:?} -> {:?}", input, output);
@@ -156,15 +401,92 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             output,
             rate,
             channels,
+            bits,
+            afade,
+            allow_placeholder,
         }) => {
-            println!("Transcode command: {:?} -> {:?}", input, output);
-            if let Some(r) = rate {
-                println!("  Sample rate: {}", r);
+            use ffmpeg_rs::encoder::{Encoder, WavEncoder};
+            use ffmpeg_rs::filter::{Fade, Filter, Remix, Resample};
+
+            let format = match bits {
+                Some(bits) => sample_format_from_bits(bits)?,
+                None => SampleFormat::F32,
+            };
+            let target_channels = channels.as_deref().map(parse_channels_arg).transpose()?;
+
+            let mut decoder = ffmpeg_rs::decoder::from_file(&input)?;
+            check_placeholder_audio(decoder.as_ref(), &input, allow_placeholder)?;
+
+            let mut fade = match &afade {
+                Some(spec) => {
+                    let mut fade = Fade::new();
+                    if spec.contains("end-") {
+                        fade = fade.with_total_duration(decoder.total_duration()?);
+                    }
+                    Some(parse_afade_arg(spec, fade)?)
+                }
+                None => None,
+            };
+
+            let mut resample: Option<Resample> = None;
+            let mut remix: Option<Remix> = None;
+            let mut encoder: Option<WavEncoder> = None;
+
+            while let Some(mut frame) = decoder.decode_frame()? {
+                if let Some(target_rate) = rate {
+                    if frame.sample_rate() != target_rate {
+                        let resample = match resample.as_mut() {
+                            Some(resample) => resample,
+                            None => {
+                                resample = Some(Resample::new(
+                                    frame.sample_rate(),
+                                    target_rate,
+                                    frame.channels(),
+                                )?);
+                                resample.as_mut().unwrap()
+                            }
+                        };
+                        frame = resample.process(&frame)?;
+                    }
+                }
+
+                if let Some(target_channels) = target_channels {
+                    if frame.channels() != target_channels {
+                        let remix = match remix.as_mut() {
+                            Some(remix) => remix,
+                            None => {
+                                remix = Some(Remix::new(frame.channels(), target_channels)?);
+                                remix.as_mut().unwrap()
+                            }
+                        };
+                        frame = remix.process(&frame)?;
+                    }
+                }
+
+                if let Some(fade) = fade.as_mut() {
+                    frame = fade.process(&frame)?;
+                }
+
+                let encoder = match encoder.as_mut() {
+                    Some(encoder) => encoder,
+                    None => {
+                        encoder = Some(WavEncoder::with_format(
+                            &output,
+                            frame.sample_rate(),
+                            frame.channels(),
+                            format,
+                        )?);
+                        encoder.as_mut().unwrap()
+                    }
+                };
+                encoder.encode(&frame)?;
             }
-            if let Some(c) = channels {
-                println!("  Channels: {}", c);
+
+            if let Some(mut encoder) = encoder {
+                encoder.finalize()?;
             }
-            println!("Not yet implemented - Phases 2-4");
+
+            println!("Transcoded {:?} -> {:?}", input, output);
         }
         None => {
             println!("FFmpeg-RS {} - Pure Rust Audio Processing", ffmpeg_rs::VERSION);
@@ -192,6 +514,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("ffmpeg-rs <command> [options]");
             println!("\nAvailable commands:");
             println!("  probe       - Audio file information (framework ready)");
+            println!("  analyze     - Spectral/temporal feature extraction (JSON output)");
             println!("  decode      - Decode to WAV (decoder stub)");
             println!("  encode      - Encode from WAV");
             println!("  segment     - Split audio into chunks");
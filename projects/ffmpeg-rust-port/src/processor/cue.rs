@@ -0,0 +1,291 @@
+//! CUE sheet parsing for track-boundary-driven segmentation
+
+use crate::error::{AudioError, AudioResult};
+use std::time::Duration;
+
+/// Parse an `MM:SS:FF` CUE sheet timestamp (`FF` is CD frames, 75 per second)
+fn parse_cue_time(text: &str) -> AudioResult<Duration> {
+    let parts: Vec<&str> = text.split(':').collect();
+    if parts.len() != 3 {
+        return Err(invalid_time(text));
+    }
+
+    let minutes: u64 = parts[0].parse().map_err(|_| invalid_time(text))?;
+    let seconds: u64 = parts[1].parse().map_err(|_| invalid_time(text))?;
+    let frames: u64 = parts[2].parse().map_err(|_| invalid_time(text))?;
+
+    let secs = (minutes * 60 + seconds) as f64 + frames as f64 / 75.0;
+    Ok(Duration::from_secs_f64(secs))
+}
+
+fn invalid_time(text: &str) -> AudioError {
+    AudioError::ProcessingError(format!(
+        "Invalid CUE INDEX time '{text}', expected MM:SS:FF"
+    ))
+}
+
+/// Strip a CUE field value of its surrounding quotes, if any
+fn unquote(text: &str) -> String {
+    let trimmed = text.trim();
+    trimmed
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(trimmed)
+        .to_string()
+}
+
+/// One `TRACK` entry parsed from a CUE sheet
+#[derive(Debug, Clone, Default)]
+pub struct CueTrack {
+    /// Track number as it appears in the sheet (`TRACK nn AUDIO`)
+    pub number: u32,
+    /// Name of the `FILE` this track belongs to
+    pub file: String,
+    /// `TITLE`, if present
+    pub title: Option<String>,
+    /// `PERFORMER`, if present
+    pub performer: Option<String>,
+    /// `INDEX 00` pregap start, relative to the start of `file`
+    pub pregap: Option<Duration>,
+    /// `INDEX 01` track start, relative to the start of `file`
+    pub start: Duration,
+}
+
+/// A parsed CUE sheet: an ordered list of tracks across one or more `FILE`s
+#[derive(Debug, Clone, Default)]
+pub struct CueSheet {
+    /// Tracks in sheet order
+    pub tracks: Vec<CueTrack>,
+}
+
+impl CueSheet {
+    /// Parse CUE sheet text into track boundaries
+    ///
+    /// Recognizes `FILE`, `TRACK nn AUDIO`, `TITLE`, `PERFORMER`, and
+    /// `INDEX 00`/`INDEX 01`; every other command (`REM`, `CATALOG`, ...) is
+    /// ignored.
+    pub fn parse(text: &str) -> AudioResult<Self> {
+        let mut tracks = Vec::new();
+        let mut current_file = String::new();
+        let mut current: Option<CueTrack> = None;
+
+        for raw_line in text.lines() {
+            let line = raw_line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (command, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let rest = rest.trim();
+
+            match command.to_ascii_uppercase().as_str() {
+                "FILE" => {
+                    // `FILE "name" WAVE` - drop the trailing type keyword.
+                    let name_part = rest
+                        .rsplit_once(char::is_whitespace)
+                        .map_or(rest, |(name, _format)| name);
+                    current_file = unquote(name_part);
+                }
+                "TRACK" => {
+                    if let Some(track) = current.take() {
+                        tracks.push(track);
+                    }
+                    let number: u32 = rest
+                        .split_whitespace()
+                        .next()
+                        .and_then(|n| n.parse().ok())
+                        .ok_or_else(|| {
+                            AudioError::ProcessingError(format!("Invalid TRACK line '{line}'"))
+                        })?;
+                    current = Some(CueTrack {
+                        number,
+                        file: current_file.clone(),
+                        ..Default::default()
+                    });
+                }
+                "TITLE" => {
+                    if let Some(track) = current.as_mut() {
+                        track.title = Some(unquote(rest));
+                    }
+                }
+                "PERFORMER" => {
+                    if let Some(track) = current.as_mut() {
+                        track.performer = Some(unquote(rest));
+                    }
+                }
+                "INDEX" => {
+                    let mut fields = rest.split_whitespace();
+                    let index_num = fields.next().unwrap_or("");
+                    let time_text = fields.next().unwrap_or("");
+                    let time = parse_cue_time(time_text)?;
+
+                    if let Some(track) = current.as_mut() {
+                        match index_num {
+                            "00" => track.pregap = Some(time),
+                            "01" => track.start = time,
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(track) = current.take() {
+            tracks.push(track);
+        }
+
+        Ok(CueSheet { tracks })
+    }
+
+    /// Compute each track's absolute sample offset into the concatenation of
+    /// every referenced `FILE`, given each file's length in samples (per
+    /// channel), in the order each `FILE` is first referenced by the sheet
+    pub fn absolute_track_starts(
+        &self,
+        sample_rate: u32,
+        file_lengths_samples: &[u64],
+    ) -> AudioResult<Vec<u64>> {
+        self.absolute_offsets(sample_rate, file_lengths_samples, |track| track.start)
+    }
+
+    /// Compute each track's absolute sample offset the same way as
+    /// [`absolute_track_starts`](Self::absolute_track_starts), except a
+    /// track with an `INDEX 00` pregap starts at the pregap rather than at
+    /// `INDEX 01`, so the pregap audio is folded into the track that follows
+    /// it instead of silently belonging to nobody
+    pub fn absolute_segment_starts(
+        &self,
+        sample_rate: u32,
+        file_lengths_samples: &[u64],
+    ) -> AudioResult<Vec<u64>> {
+        self.absolute_offsets(sample_rate, file_lengths_samples, |track| {
+            track.pregap.unwrap_or(track.start)
+        })
+    }
+
+    fn absolute_offsets(
+        &self,
+        sample_rate: u32,
+        file_lengths_samples: &[u64],
+        time_for: impl Fn(&CueTrack) -> Duration,
+    ) -> AudioResult<Vec<u64>> {
+        let mut file_order: Vec<&str> = Vec::new();
+        for track in &self.tracks {
+            if !file_order.contains(&track.file.as_str()) {
+                file_order.push(&track.file);
+            }
+        }
+
+        if file_order.len() > file_lengths_samples.len() {
+            return Err(AudioError::ProcessingError(format!(
+                "CUE sheet references {} file(s) but only {} length(s) were given",
+                file_order.len(),
+                file_lengths_samples.len()
+            )));
+        }
+
+        let mut file_offsets = vec![0u64; file_order.len()];
+        let mut running = 0u64;
+        for (i, &len) in file_lengths_samples.iter().enumerate().take(file_order.len()) {
+            file_offsets[i] = running;
+            running += len;
+        }
+
+        self.tracks
+            .iter()
+            .map(|track| {
+                let file_index = file_order
+                    .iter()
+                    .position(|&f| f == track.file)
+                    .expect("file_order was built from these tracks");
+                let offset_in_file =
+                    (time_for(track).as_secs_f64() * sample_rate as f64).round() as u64;
+                Ok(file_offsets[file_index] + offset_in_file)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHEET: &str = r#"
+        FILE "album.wav" WAVE
+          TRACK 01 AUDIO
+            TITLE "Intro"
+            PERFORMER "Band"
+            INDEX 00 00:00:00
+            INDEX 01 00:02:00
+          TRACK 02 AUDIO
+            TITLE "Second Song"
+            PERFORMER "Band"
+            INDEX 01 03:10:37
+    "#;
+
+    #[test]
+    fn test_parse_tracks_and_metadata() {
+        let sheet = CueSheet::parse(SHEET).unwrap();
+        assert_eq!(sheet.tracks.len(), 2);
+        assert_eq!(sheet.tracks[0].title.as_deref(), Some("Intro"));
+        assert_eq!(sheet.tracks[0].pregap, Some(Duration::from_secs(0)));
+        assert_eq!(sheet.tracks[1].title.as_deref(), Some("Second Song"));
+    }
+
+    #[test]
+    fn test_parse_index_time() {
+        // 3:10:37 -> 190 seconds + 37/75
+        let time = parse_cue_time("03:10:37").unwrap();
+        assert!((time.as_secs_f64() - (190.0 + 37.0 / 75.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_absolute_track_starts_single_file() {
+        let sheet = CueSheet::parse(SHEET).unwrap();
+        let starts = sheet.absolute_track_starts(44100, &[10_000_000]).unwrap();
+
+        assert_eq!(starts[0], (2.0 * 44100.0) as u64);
+        assert_eq!(starts.len(), 2);
+    }
+
+    #[test]
+    fn test_absolute_segment_starts_folds_pregap_into_following_track() {
+        let text = r#"
+            FILE "album.wav" WAVE
+              TRACK 01 AUDIO
+                INDEX 01 00:00:00
+              TRACK 02 AUDIO
+                INDEX 00 03:00:00
+                INDEX 01 03:02:00
+        "#;
+        let sheet = CueSheet::parse(text).unwrap();
+
+        let track_starts = sheet.absolute_track_starts(44100, &[10_000_000]).unwrap();
+        assert_eq!(track_starts[1], (182.0 * 44100.0) as u64);
+
+        let segment_starts = sheet.absolute_segment_starts(44100, &[10_000_000]).unwrap();
+        assert_eq!(segment_starts[1], (180.0 * 44100.0) as u64);
+    }
+
+    #[test]
+    fn test_absolute_track_starts_multiple_files() {
+        let text = r#"
+            FILE "one.wav" WAVE
+              TRACK 01 AUDIO
+                INDEX 01 00:00:00
+            FILE "two.wav" WAVE
+              TRACK 02 AUDIO
+                INDEX 01 00:05:00
+        "#;
+        let sheet = CueSheet::parse(text).unwrap();
+        let starts = sheet
+            .absolute_track_starts(44100, &[44100 * 10, 44100 * 10])
+            .unwrap();
+
+        assert_eq!(starts[0], 0);
+        // Track 2 starts 5 seconds into the second file, which itself
+        // starts after the first file's 10 seconds.
+        assert_eq!(starts[1], 44100 * 10 + 44100 * 5);
+    }
+}
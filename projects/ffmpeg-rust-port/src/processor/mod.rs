@@ -1,8 +1,12 @@
 //! Audio processing pipeline implementations
 
+pub mod cue;
 pub mod segment;
+pub mod stream;
 
+pub use cue::{CueSheet, CueTrack};
 pub use segment::Segment;
+pub use stream::{FrameStream, PcmBuffers};
 
 /// Audio processing pipeline result
 #[derive(Debug)]
@@ -1,20 +1,50 @@
-use crate::core::{AudioFrame, Channels};
+use super::cue::CueSheet;
+use crate::core::{AudioFrame, AudioMetadata, Channels};
 use crate::error::{AudioError, AudioResult};
 use std::time::Duration;
 
-/// Audio segmentation - split audio into time-based chunks
+/// A single track boundary computed from a CUE sheet, with the metadata
+/// that should be attached to the segment starting there
+#[derive(Debug, Clone)]
+struct TrackBoundary {
+    start_sample: u64,
+    title: Option<String>,
+    performer: Option<String>,
+}
+
+/// Audio segmentation - split audio into time-based or CUE-sheet-driven chunks
 #[derive(Debug, Clone)]
 pub struct Segment {
-    /// Segment duration
+    /// Segment duration (fixed-duration mode)
     duration: Duration,
     /// Sample rate
     sample_rate: u32,
     /// Segment index counter
     segment_index: u32,
+    /// Track boundaries when segmenting by CUE sheet instead of duration
+    cue_boundaries: Option<Vec<TrackBoundary>>,
+    /// Global sample position (samples per channel) consumed so far across
+    /// successive `split_by_cue` calls
+    cue_position: u64,
+    /// Index into `cue_boundaries` of the track currently being emitted
+    cue_track_idx: usize,
+    /// Window length in samples per channel, when segmenting by overlapping
+    /// windows instead of duration or CUE sheet
+    window_len: Option<usize>,
+    /// Hop size in samples per channel (windowed mode only)
+    hop_len: usize,
+    /// Whether `finish_windowed` zero-pads the trailing partial window to
+    /// `window_len` instead of dropping it
+    pad_final: bool,
+    /// Buffered interleaved samples awaiting a full window (windowed mode)
+    window_buffer: Vec<f32>,
+    /// Channel layout of the stream being windowed, learned from the first
+    /// frame passed to `split_windowed`
+    window_channels: Option<Channels>,
 }
 
 impl Segment {
-    /// Create a new segmenter
+    /// Create a new fixed-duration segmenter
     pub fn new(duration: Duration, sample_rate: u32) -> AudioResult<Self> {
         if sample_rate == 0 {
             return Err(AudioError::InvalidSampleRate { rate: 0 });
@@ -24,6 +54,99 @@ impl Segment {
             duration,
             sample_rate,
             segment_index: 0,
+            cue_boundaries: None,
+            cue_position: 0,
+            cue_track_idx: 0,
+            window_len: None,
+            hop_len: 0,
+            pad_final: false,
+            window_buffer: Vec::new(),
+            window_channels: None,
+        })
+    }
+
+    /// Create a segmenter that splits at the track boundaries described by a
+    /// CUE sheet instead of at fixed durations
+    ///
+    /// `file_lengths_samples` gives the length (samples per channel) of each
+    /// `FILE` the sheet references, in the order it first references them -
+    /// needed to compute absolute offsets when a sheet spans multiple files.
+    ///
+    /// A track with an `INDEX 00` pregap is split starting at the pregap, so
+    /// that audio ends up in the segment for the track it precedes rather
+    /// than being silently dropped into the tail of the previous one.
+    pub fn from_cue(
+        cue: &CueSheet,
+        sample_rate: u32,
+        file_lengths_samples: &[u64],
+    ) -> AudioResult<Self> {
+        if sample_rate == 0 {
+            return Err(AudioError::InvalidSampleRate { rate: 0 });
+        }
+
+        let starts = cue.absolute_segment_starts(sample_rate, file_lengths_samples)?;
+        let boundaries = cue
+            .tracks
+            .iter()
+            .zip(starts)
+            .map(|(track, start_sample)| TrackBoundary {
+                start_sample,
+                title: track.title.clone(),
+                performer: track.performer.clone(),
+            })
+            .collect();
+
+        Ok(Segment {
+            duration: Duration::ZERO,
+            sample_rate,
+            segment_index: 0,
+            cue_boundaries: Some(boundaries),
+            cue_position: 0,
+            cue_track_idx: 0,
+            window_len: None,
+            hop_len: 0,
+            pad_final: false,
+            window_buffer: Vec::new(),
+            window_channels: None,
+        })
+    }
+
+    /// Create a segmenter that emits fixed-length, overlapping (or disjoint)
+    /// windows advancing by `hop` instead of whole duration-sized blocks -
+    /// the standard front-end for a feature-extraction pipeline
+    ///
+    /// `pad_final` selects what happens to a trailing partial window once
+    /// the stream ends: zero-padded to `window` if `true`, dropped if `false`.
+    pub fn windowed(
+        window: Duration,
+        hop: Duration,
+        sample_rate: u32,
+        pad_final: bool,
+    ) -> AudioResult<Self> {
+        if sample_rate == 0 {
+            return Err(AudioError::InvalidSampleRate { rate: 0 });
+        }
+
+        let window_len = (window.as_secs_f64() * sample_rate as f64).round() as usize;
+        let hop_len = (hop.as_secs_f64() * sample_rate as f64).round() as usize;
+        if window_len == 0 || hop_len == 0 {
+            return Err(AudioError::ConfigError(
+                "window and hop must both be non-zero".to_string(),
+            ));
+        }
+
+        Ok(Segment {
+            duration: window,
+            sample_rate,
+            segment_index: 0,
+            cue_boundaries: None,
+            cue_position: 0,
+            cue_track_idx: 0,
+            window_len: Some(window_len),
+            hop_len,
+            pad_final,
+            window_buffer: Vec::new(),
+            window_channels: None,
         })
     }
 
@@ -32,6 +155,28 @@ impl Segment {
         (self.duration.as_secs_f64() * self.sample_rate as f64).ceil() as usize
     }
 
+    /// Extract the sample range `[start_sample, end_sample)` (per channel)
+    /// from a single already-decoded frame
+    pub fn extract(frame: &AudioFrame, start_sample: usize, end_sample: usize) -> AudioResult<AudioFrame> {
+        if end_sample < start_sample {
+            return Err(AudioError::ConfigError(
+                "end_sample must be >= start_sample".to_string(),
+            ));
+        }
+
+        let samples_per_channel = frame.samples_per_channel();
+        if end_sample > samples_per_channel {
+            return Err(AudioError::BufferError(format!(
+                "Requested range {}..{} exceeds frame length {}",
+                start_sample, end_sample, samples_per_channel
+            )));
+        }
+
+        let num_channels = frame.channels().count() as usize;
+        let samples = frame.samples()[start_sample * num_channels..end_sample * num_channels].to_vec();
+        AudioFrame::new(samples, frame.sample_rate(), frame.channels(), frame.frame_number())
+    }
+
     /// Split frame(s) by duration into segments
     pub fn split_frame(&mut self, frame: &AudioFrame) -> AudioResult<Vec<AudioFrame>> {
         if frame.sample_rate() != self.sample_rate {
@@ -82,14 +227,173 @@ impl Segment {
         Ok(segments)
     }
 
+    /// Split incoming audio at CUE-sheet track boundaries, carrying each
+    /// track's title/performer into the emitted segment's `AudioMetadata`
+    ///
+    /// Frames are expected to arrive in stream order across successive
+    /// calls; the last track always runs to end-of-stream (there is no
+    /// final boundary to stop at).
+    pub fn split_by_cue(&mut self, frame: &AudioFrame) -> AudioResult<Vec<(AudioFrame, AudioMetadata)>> {
+        let boundaries = self.cue_boundaries.clone().ok_or_else(|| {
+            AudioError::ConfigError("Segment was not constructed with a CUE sheet".to_string())
+        })?;
+
+        if frame.sample_rate() != self.sample_rate {
+            return Err(AudioError::InvalidSampleRate {
+                rate: frame.sample_rate(),
+            });
+        }
+
+        let num_channels = frame.channels().count() as usize;
+        let samples = frame.samples();
+        let samples_per_channel = frame.samples_per_channel();
+
+        let mut output = Vec::new();
+        let mut local_start = 0usize;
+
+        while local_start < samples_per_channel {
+            let global_pos = self.cue_position + local_start as u64;
+
+            while self.cue_track_idx + 1 < boundaries.len()
+                && boundaries[self.cue_track_idx + 1].start_sample <= global_pos
+            {
+                self.cue_track_idx += 1;
+            }
+
+            let local_end = match boundaries.get(self.cue_track_idx + 1) {
+                Some(next) => {
+                    let next_local = (next.start_sample - self.cue_position) as usize;
+                    next_local.min(samples_per_channel)
+                }
+                None => samples_per_channel,
+            };
+
+            if local_end <= local_start {
+                break;
+            }
+
+            let segment_samples =
+                samples[local_start * num_channels..local_end * num_channels].to_vec();
+            let segment_frame = AudioFrame::new(
+                segment_samples,
+                self.sample_rate,
+                frame.channels(),
+                self.segment_index as u64,
+            )?;
+
+            let boundary = &boundaries[self.cue_track_idx];
+            let mut metadata =
+                AudioMetadata::new(self.sample_rate, frame.channels(), "CUE".to_string())?
+                    .with_duration(segment_frame.duration());
+            if let Some(title) = &boundary.title {
+                metadata = metadata.with_title(title.clone());
+            }
+            if let Some(performer) = &boundary.performer {
+                metadata = metadata.with_performer(performer.clone());
+            }
+
+            output.push((segment_frame, metadata));
+            self.segment_index += 1;
+            local_start = local_end;
+        }
+
+        self.cue_position += samples_per_channel as u64;
+        Ok(output)
+    }
+
+    /// Split incoming audio into fixed-length windows advancing by `hop`,
+    /// buffering any remainder for the next call so windows can overlap
+    /// across `AudioFrame` boundaries
+    pub fn split_windowed(&mut self, frame: &AudioFrame) -> AudioResult<Vec<AudioFrame>> {
+        let window_len = self.window_len.ok_or_else(|| {
+            AudioError::ConfigError("Segment was not constructed in windowed mode".to_string())
+        })?;
+
+        if frame.sample_rate() != self.sample_rate {
+            return Err(AudioError::InvalidSampleRate {
+                rate: frame.sample_rate(),
+            });
+        }
+
+        let channels = frame.channels();
+        match self.window_channels {
+            Some(existing) if existing != channels => {
+                return Err(AudioError::InvalidChannels {
+                    expected: existing.count(),
+                    got: channels.count(),
+                })
+            }
+            _ => self.window_channels = Some(channels),
+        }
+        let num_channels = channels.count() as usize;
+
+        self.window_buffer.extend_from_slice(frame.samples());
+
+        let mut output = Vec::new();
+        let window_samples = window_len * num_channels;
+        let hop_samples = self.hop_len * num_channels;
+
+        while self.window_buffer.len() >= window_samples {
+            let segment_samples = self.window_buffer[..window_samples].to_vec();
+            output.push(AudioFrame::new(
+                segment_samples,
+                self.sample_rate,
+                channels,
+                self.segment_index as u64,
+            )?);
+            self.segment_index += 1;
+
+            let drain = hop_samples.min(self.window_buffer.len());
+            self.window_buffer.drain(0..drain);
+        }
+
+        Ok(output)
+    }
+
+    /// Finalize windowed splitting: handles the trailing partial window per
+    /// `pad_final` (zero-padded if `true`, dropped if `false`) and clears any
+    /// buffered state
+    pub fn finish_windowed(&mut self) -> AudioResult<Option<AudioFrame>> {
+        let window_len = self.window_len.ok_or_else(|| {
+            AudioError::ConfigError("Segment was not constructed in windowed mode".to_string())
+        })?;
+
+        let channels = match self.window_channels {
+            Some(channels) => channels,
+            None => return Ok(None),
+        };
+        let num_channels = channels.count() as usize;
+
+        if self.window_buffer.is_empty() {
+            return Ok(None);
+        }
+
+        let frame = if self.pad_final {
+            let mut samples = std::mem::take(&mut self.window_buffer);
+            samples.resize(window_len * num_channels, 0.0);
+            let frame = AudioFrame::new(samples, self.sample_rate, channels, self.segment_index as u64)?;
+            self.segment_index += 1;
+            Some(frame)
+        } else {
+            self.window_buffer.clear();
+            None
+        };
+
+        Ok(frame)
+    }
+
     /// Get the current segment index
     pub fn segment_index(&self) -> u32 {
         self.segment_index
     }
 
-    /// Reset segment counter
+    /// Reset segment counter (and CUE/windowed playback position, if any)
     pub fn reset(&mut self) {
         self.segment_index = 0;
+        self.cue_position = 0;
+        self.cue_track_idx = 0;
+        self.window_buffer.clear();
+        self.window_channels = None;
     }
 }
 
@@ -128,4 +432,161 @@ mod tests {
         assert_eq!(segments.len(), 2);
         assert_eq!(segments[0].samples_per_channel(), 44100);
     }
+
+    const SHEET: &str = r#"
+        FILE "album.wav" WAVE
+          TRACK 01 AUDIO
+            TITLE "Intro"
+            PERFORMER "Band"
+            INDEX 01 00:00:00
+          TRACK 02 AUDIO
+            TITLE "Second Song"
+            PERFORMER "Band"
+            INDEX 01 00:01:00
+    "#;
+
+    #[test]
+    fn test_split_by_cue_carries_title_and_performer() {
+        let cue = CueSheet::parse(SHEET).unwrap();
+        let mut segment = Segment::from_cue(&cue, 10, &[1000]).unwrap();
+
+        // 2 seconds at 10Hz mono; track 2 starts at sample 10 (1 second in).
+        let frame = AudioFrame::new(vec![0.0; 20], 10, Channels::Mono, 0).unwrap();
+        let segments = segment.split_by_cue(&frame).unwrap();
+
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].0.samples_per_channel(), 10);
+        assert_eq!(segments[0].1.title.as_deref(), Some("Intro"));
+        assert_eq!(segments[1].0.samples_per_channel(), 10);
+        assert_eq!(segments[1].1.title.as_deref(), Some("Second Song"));
+    }
+
+    #[test]
+    fn test_split_by_cue_continues_across_calls() {
+        let cue = CueSheet::parse(SHEET).unwrap();
+        let mut segment = Segment::from_cue(&cue, 10, &[1000]).unwrap();
+
+        // Feed the same stream in two 1-second frames instead of one 2-second frame.
+        let frame1 = AudioFrame::new(vec![0.0; 10], 10, Channels::Mono, 0).unwrap();
+        let frame2 = AudioFrame::new(vec![0.0; 10], 10, Channels::Mono, 1).unwrap();
+
+        let first = segment.split_by_cue(&frame1).unwrap();
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].1.title.as_deref(), Some("Intro"));
+
+        let second = segment.split_by_cue(&frame2).unwrap();
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].1.title.as_deref(), Some("Second Song"));
+    }
+
+    #[test]
+    fn test_split_by_cue_starts_track_at_its_pregap() {
+        const SHEET_WITH_PREGAP: &str = r#"
+            FILE "album.wav" WAVE
+              TRACK 01 AUDIO
+                TITLE "Intro"
+                INDEX 01 00:00:00
+              TRACK 02 AUDIO
+                TITLE "Second Song"
+                INDEX 00 00:01:00
+                INDEX 01 00:02:00
+        "#;
+        let cue = CueSheet::parse(SHEET_WITH_PREGAP).unwrap();
+        let mut segment = Segment::from_cue(&cue, 10, &[1000]).unwrap();
+
+        // 3 seconds at 10Hz mono; the pregap pulls track 2's segment back to
+        // sample 10 (1 second in) instead of sample 20 (INDEX 01).
+        let frame = AudioFrame::new(vec![0.0; 30], 10, Channels::Mono, 0).unwrap();
+        let segments = segment.split_by_cue(&frame).unwrap();
+
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].0.samples_per_channel(), 10);
+        assert_eq!(segments[1].0.samples_per_channel(), 20);
+        assert_eq!(segments[1].1.title.as_deref(), Some("Second Song"));
+    }
+
+    #[test]
+    fn test_split_by_cue_without_cue_sheet_errors() {
+        let mut segment = Segment::new(Duration::from_secs(1), 44100).unwrap();
+        let frame = AudioFrame::new(vec![0.0; 4], 44100, Channels::Mono, 0).unwrap();
+        assert!(segment.split_by_cue(&frame).is_err());
+    }
+
+    #[test]
+    fn test_extract_sample_range() {
+        let samples: Vec<f32> = (0..20).map(|i| i as f32).collect();
+        let frame = AudioFrame::new(samples, 10, Channels::Stereo, 0).unwrap();
+
+        let extracted = Segment::extract(&frame, 2, 5).unwrap();
+        assert_eq!(extracted.samples_per_channel(), 3);
+        assert_eq!(extracted.samples(), &[4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    }
+
+    #[test]
+    fn test_extract_rejects_invalid_range() {
+        let frame = AudioFrame::new(vec![0.0; 20], 10, Channels::Stereo, 0).unwrap();
+        assert!(Segment::extract(&frame, 5, 2).is_err());
+        assert!(Segment::extract(&frame, 0, 11).is_err());
+    }
+
+    #[test]
+    fn test_split_windowed_overlapping_advances_by_hop() {
+        let mut segment = Segment::windowed(
+            Duration::from_secs_f64(0.4),
+            Duration::from_secs_f64(0.2),
+            10,
+            false,
+        )
+        .unwrap();
+
+        // 1 second of mono audio at 10Hz: window=4 samples, hop=2 samples.
+        let samples: Vec<f32> = (0..10).map(|i| i as f32).collect();
+        let frame = AudioFrame::new(samples, 10, Channels::Mono, 0).unwrap();
+
+        let windows = segment.split_windowed(&frame).unwrap();
+
+        // Windows start at 0, 2, 4, 6 (the last full window is 6..10; 8 would
+        // need samples 8..12, so the remaining [8,9] is buffered as a partial
+        // window).
+        assert_eq!(windows.len(), 4);
+        assert_eq!(windows[0].samples(), &[0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(windows[1].samples(), &[2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(windows[2].samples(), &[4.0, 5.0, 6.0, 7.0]);
+        assert_eq!(windows[3].samples(), &[6.0, 7.0, 8.0, 9.0]);
+    }
+
+    #[test]
+    fn test_finish_windowed_drops_partial_by_default() {
+        let mut segment =
+            Segment::windowed(Duration::from_secs_f64(0.4), Duration::from_secs_f64(0.2), 10, false)
+                .unwrap();
+
+        let samples: Vec<f32> = (0..10).map(|i| i as f32).collect();
+        let frame = AudioFrame::new(samples, 10, Channels::Mono, 0).unwrap();
+        segment.split_windowed(&frame).unwrap();
+
+        assert!(segment.finish_windowed().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_finish_windowed_pads_partial_when_enabled() {
+        let mut segment =
+            Segment::windowed(Duration::from_secs_f64(0.4), Duration::from_secs_f64(0.2), 10, true)
+                .unwrap();
+
+        let samples: Vec<f32> = (0..10).map(|i| i as f32).collect();
+        let frame = AudioFrame::new(samples, 10, Channels::Mono, 0).unwrap();
+        segment.split_windowed(&frame).unwrap();
+
+        let last = segment.finish_windowed().unwrap().unwrap();
+        assert_eq!(last.samples(), &[8.0, 9.0, 0.0, 0.0]);
+        assert!(segment.finish_windowed().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_split_windowed_requires_windowed_mode() {
+        let mut segment = Segment::new(Duration::from_secs(1), 44100).unwrap();
+        let frame = AudioFrame::new(vec![0.0; 4], 44100, Channels::Mono, 0).unwrap();
+        assert!(segment.split_windowed(&frame).is_err());
+    }
 }
@@ -0,0 +1,243 @@
+use crate::core::{AudioFrame, Channels};
+use crate::error::AudioResult;
+use std::collections::VecDeque;
+
+/// Ring buffer of pending decoded PCM chunks awaiting consumption
+///
+/// Chunks are kept as-received (no copy into one contiguous buffer);
+/// `consume_exact` drains samples from the front chunk(s) as a consumer
+/// pulls fixed-size blocks, popping chunks once fully read.
+#[derive(Debug, Default)]
+pub struct PcmBuffers {
+    chunks: VecDeque<Vec<f32>>,
+    /// Read cursor into the front chunk
+    cursor: usize,
+    /// Total unread samples across all buffered chunks
+    available: usize,
+}
+
+impl PcmBuffers {
+    /// Create an empty ring
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push a newly decoded chunk onto the back of the ring
+    pub fn push(&mut self, chunk: Vec<f32>) {
+        if chunk.is_empty() {
+            return;
+        }
+        self.available += chunk.len();
+        self.chunks.push_back(chunk);
+    }
+
+    /// Number of samples currently buffered and ready to consume
+    pub fn samples_available(&self) -> usize {
+        self.available
+    }
+
+    /// Pop exactly `out.len()` samples into `out`, draining front chunks as
+    /// they're exhausted. Returns `false` (leaving `out` and the buffer
+    /// untouched) if fewer than `out.len()` samples are currently buffered.
+    pub fn consume_exact(&mut self, out: &mut [f32]) -> bool {
+        if out.len() > self.available {
+            return false;
+        }
+
+        let mut filled = 0;
+        while filled < out.len() {
+            let front = self
+                .chunks
+                .front_mut()
+                .expect("available tracks the sum of buffered chunk lengths");
+            let remaining_in_front = front.len() - self.cursor;
+            let take = remaining_in_front.min(out.len() - filled);
+
+            out[filled..filled + take].copy_from_slice(&front[self.cursor..self.cursor + take]);
+            filled += take;
+            self.cursor += take;
+
+            if self.cursor == front.len() {
+                self.chunks.pop_front();
+                self.cursor = 0;
+            }
+        }
+
+        self.available -= out.len();
+        true
+    }
+}
+
+/// Pulls fixed-size `AudioFrame`s out of a source that produces
+/// variable-length decoded chunks, buffering any remainder in an internal
+/// [`PcmBuffers`] ring
+///
+/// `source` is polled for more interleaved PCM whenever the ring doesn't yet
+/// hold a full frame; it returns `Ok(None)` once the underlying stream (e.g.
+/// a `Decoder`) is exhausted. The final partial frame, if any samples remain
+/// buffered at that point, is still emitted short rather than dropped,
+/// provided the remainder is a whole number of sample groups; otherwise the
+/// stream ends without it.
+pub struct FrameStream<F> {
+    source: F,
+    buffers: PcmBuffers,
+    /// Samples per channel in each emitted frame
+    frame_len: usize,
+    sample_rate: u32,
+    channels: Channels,
+    frame_number: u64,
+    exhausted: bool,
+}
+
+impl<F> FrameStream<F>
+where
+    F: FnMut() -> AudioResult<Option<Vec<f32>>>,
+{
+    /// Create a new stream pulling `frame_len`-sample (per channel) frames
+    pub fn new(source: F, frame_len: usize, sample_rate: u32, channels: Channels) -> Self {
+        FrameStream {
+            source,
+            buffers: PcmBuffers::new(),
+            frame_len,
+            sample_rate,
+            channels,
+            frame_number: 0,
+            exhausted: false,
+        }
+    }
+
+    fn frame_samples(&self) -> usize {
+        self.frame_len * self.channels.count() as usize
+    }
+
+    fn emit(&mut self, samples: Vec<f32>) -> AudioResult<AudioFrame> {
+        let frame = AudioFrame::new(samples, self.sample_rate, self.channels, self.frame_number)?;
+        self.frame_number += 1;
+        Ok(frame)
+    }
+
+    /// Pull the next fixed-size frame, or `None` once the source is
+    /// exhausted and no samples remain buffered
+    pub fn next_frame(&mut self) -> AudioResult<Option<AudioFrame>> {
+        let needed = self.frame_samples();
+
+        while self.buffers.samples_available() < needed && !self.exhausted {
+            match (self.source)()? {
+                Some(chunk) => self.buffers.push(chunk),
+                None => self.exhausted = true,
+            }
+        }
+
+        if self.buffers.samples_available() >= needed {
+            let mut samples = vec![0.0; needed];
+            self.buffers.consume_exact(&mut samples);
+            return Ok(Some(self.emit(samples)?));
+        }
+
+        // Source is exhausted with fewer than a full frame left: emit a
+        // short final frame instead of silently dropping the tail.
+        let remaining = self.buffers.samples_available();
+        if remaining == 0 || remaining % self.channels.count() as usize != 0 {
+            return Ok(None);
+        }
+
+        let mut tail = vec![0.0; remaining];
+        self.buffers.consume_exact(&mut tail);
+        Ok(Some(self.emit(tail)?))
+    }
+}
+
+impl<F> Iterator for FrameStream<F>
+where
+    F: FnMut() -> AudioResult<Option<Vec<f32>>>,
+{
+    type Item = AudioResult<AudioFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_frame() {
+            Ok(Some(frame)) => Some(Ok(frame)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pcm_buffers_push_consume_across_chunks() {
+        let mut buffers = PcmBuffers::new();
+        buffers.push(vec![1.0, 2.0]);
+        buffers.push(vec![3.0, 4.0, 5.0]);
+        assert_eq!(buffers.samples_available(), 5);
+
+        let mut out = [0.0; 4];
+        assert!(buffers.consume_exact(&mut out));
+        assert_eq!(out, [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(buffers.samples_available(), 1);
+    }
+
+    #[test]
+    fn test_pcm_buffers_consume_exact_fails_when_insufficient() {
+        let mut buffers = PcmBuffers::new();
+        buffers.push(vec![1.0, 2.0]);
+
+        let mut out = [0.0; 3];
+        assert!(!buffers.consume_exact(&mut out));
+        // Nothing should have been consumed on failure.
+        assert_eq!(buffers.samples_available(), 2);
+    }
+
+    #[test]
+    fn test_frame_stream_splits_variable_chunks_into_fixed_frames() {
+        let mut chunks = VecDeque::from(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0],
+            vec![5.0, 6.0, 7.0, 8.0],
+        ]);
+
+        let mut stream = FrameStream::new(
+            move || Ok(chunks.pop_front()),
+            2,
+            44100,
+            Channels::Mono,
+        );
+
+        let first = stream.next_frame().unwrap().unwrap();
+        assert_eq!(first.samples(), &[1.0, 2.0]);
+
+        let second = stream.next_frame().unwrap().unwrap();
+        assert_eq!(second.samples(), &[3.0, 4.0]);
+
+        let third = stream.next_frame().unwrap().unwrap();
+        assert_eq!(third.samples(), &[5.0, 6.0]);
+
+        let fourth = stream.next_frame().unwrap().unwrap();
+        assert_eq!(fourth.samples(), &[7.0, 8.0]);
+
+        assert!(stream.next_frame().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_frame_stream_emits_final_short_frame() {
+        let mut chunks = VecDeque::from(vec![vec![1.0, 2.0, 3.0]]);
+
+        let mut stream = FrameStream::new(
+            move || Ok(chunks.pop_front()),
+            2,
+            44100,
+            Channels::Mono,
+        );
+
+        let first = stream.next_frame().unwrap().unwrap();
+        assert_eq!(first.samples(), &[1.0, 2.0]);
+
+        // Only one sample remains once the source is exhausted; it should
+        // still come out as a short final frame via the Iterator impl.
+        let tail: Vec<_> = stream.collect::<AudioResult<Vec<_>>>().unwrap();
+        assert_eq!(tail.len(), 1);
+        assert_eq!(tail[0].samples(), &[3.0]);
+    }
+}